@@ -1,4 +1,5 @@
 use attribution::AttrArgs;
+use attribution::Flag;
 use proc_macro::TokenStream;
 use proc_macro2::Span;
 use quote::ToTokens;
@@ -20,6 +21,29 @@ struct AttributeArgs {
     integer: i64,
     float: f64,
     array_of_integers: Vec<i64>,
+    nested: NestedArgs,
+    missing_nested: Option<NestedArgs>,
+    #[attr_args(repeated)]
+    repeated_string: Vec<String>,
+    #[attr_args(rest)]
+    positional: Vec<String>,
+    absent_flag: Flag,
+    negated_flag: Flag,
+    #[attr_args(default)]
+    defaulted_integer: i64,
+    #[attr_args(default_with = "default_string")]
+    defaulted_string: String,
+    #[attr_args(rename = "renamed-string", alias = "aliased_string")]
+    renamed_string: String,
+}
+
+fn default_string() -> String {
+    "default".into()
+}
+
+#[derive(AttrArgs)]
+struct NestedArgs {
+    string: String,
 }
 
 #[proc_macro_attribute]
@@ -51,6 +75,32 @@ fn make_print_lines(attr: AttributeArgs) -> impl IntoIterator<Item = Stmt> {
         .map(|i| i.to_string())
         .map(|i| LitInt::new(&i, Span::call_site()));
     let array_of_integers_val: Expr = parse_quote! { vec![#(#array_of_integers),*] };
+    let nested_string_val = LitStr::new(&attr.nested.string, Span::call_site());
+    let repeated_string_val = attr
+        .repeated_string
+        .iter()
+        .map(|string| LitStr::new(string, Span::call_site()));
+    let repeated_string_val: Expr = parse_quote! { vec![#(#repeated_string_val),*] };
+    let positional_val = attr
+        .positional
+        .iter()
+        .map(|string| LitStr::new(string, Span::call_site()));
+    let positional_val: Expr = parse_quote! { vec![#(#positional_val),*] };
+    let absent_flag_val = LitBool {
+        value: attr.absent_flag.is_set(),
+        span: proc_macro2::Span::call_site(),
+    };
+    let negated_flag_val = LitBool {
+        value: attr.negated_flag.is_set(),
+        span: attr.negated_flag.span().unwrap_or_else(Span::call_site),
+    };
+    let defaulted_integer_val = LitInt::new(&attr.defaulted_integer.to_string(), Span::call_site());
+    let defaulted_string_val = LitStr::new(&attr.defaulted_string, Span::call_site());
+    let renamed_string_val = LitStr::new(&attr.renamed_string, Span::call_site());
+    let missing_nested_val = LitBool {
+        value: attr.missing_nested.is_some(),
+        span: proc_macro2::Span::call_site(),
+    };
     vec![
         parse_quote! {
             println!("flag = {}", #flag_val);
@@ -70,5 +120,32 @@ fn make_print_lines(attr: AttributeArgs) -> impl IntoIterator<Item = Stmt> {
         parse_quote! {
             println!("array_of_integers = {:?}", #array_of_integers_val);
         },
+        parse_quote! {
+            println!("nested(string = {})", #nested_string_val);
+        },
+        parse_quote! {
+            println!("missing_nested = {}", #missing_nested_val);
+        },
+        parse_quote! {
+            println!("repeated_string = {:?}", #repeated_string_val);
+        },
+        parse_quote! {
+            println!("positional = {:?}", #positional_val);
+        },
+        parse_quote! {
+            println!("absent_flag = {}", #absent_flag_val);
+        },
+        parse_quote! {
+            println!("negated_flag = {}", #negated_flag_val);
+        },
+        parse_quote! {
+            println!("defaulted_integer = {}", #defaulted_integer_val);
+        },
+        parse_quote! {
+            println!("defaulted_string = {}", #defaulted_string_val);
+        },
+        parse_quote! {
+            println!("renamed_string = {}", #renamed_string_val);
+        },
     ]
 }
@@ -1,6 +1,6 @@
 use exhaustive_attr_macros::exhaustive;
 
-#[exhaustive(flag = true, simple_flag, string = "foo", integer = 1, float = 4.0, array_of_integers = [1, 2, 3])]
+#[exhaustive("first", "second", flag = true, simple_flag, string = "foo", integer = 1, float = 4.0, array_of_integers = [1, 2, 3], nested(string = "bar"), repeated_string = "a", repeated_string = "b", !negated_flag, aliased_string = "baz")]
 fn main() {
     println!("End of main!");
 }
@@ -119,6 +119,6 @@ fn wrap_function(function: &mut ItemFn, attr: &EzTraceArgs) {
             }
         };
 
-        function.block = Box::new(new_body);
+        *function.block = new_body;
     }
 }
@@ -0,0 +1,124 @@
+/// A case convention applied to the names of every field within a struct or
+/// enum (e.g. `#[attr_args(rename_all = "kebab-case")]`).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RenameRule {
+    /// `max_age`
+    Snake,
+
+    /// `max-age`
+    Kebab,
+
+    /// `maxAge`
+    Camel,
+
+    /// `MaxAge`
+    Pascal,
+
+    /// `MAX_AGE`
+    ScreamingSnake,
+
+    /// `MAX-AGE`
+    ScreamingKebab,
+
+    /// `maxage`
+    Lower,
+
+    /// `MAXAGE`
+    Upper,
+}
+
+impl RenameRule {
+    /// The name of every rule paired with the rule it selects.
+    const RULES: &'static [(&'static str, RenameRule)] = &[
+        ("snake_case", RenameRule::Snake),
+        ("kebab-case", RenameRule::Kebab),
+        ("camelCase", RenameRule::Camel),
+        ("PascalCase", RenameRule::Pascal),
+        ("SCREAMING_SNAKE_CASE", RenameRule::ScreamingSnake),
+        ("SCREAMING-KEBAB-CASE", RenameRule::ScreamingKebab),
+        ("lowercase", RenameRule::Lower),
+        ("UPPERCASE", RenameRule::Upper),
+    ];
+
+    /// Looks up a rule by its name (e.g. `kebab-case`).
+    pub fn from_name(name: &str) -> Option<Self> {
+        RenameRule::RULES
+            .iter()
+            .find(|(rule_name, _)| *rule_name == name)
+            .map(|(_, rule)| *rule)
+    }
+
+    /// A comma separated list of the name of every rule that is used in error
+    /// messages.
+    pub fn names() -> String {
+        RenameRule::RULES
+            .iter()
+            .map(|(rule_name, _)| format!("\"{}\"", rule_name))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    /// Applies the rule to the snake case name of a field.
+    pub fn apply(self, name: &str) -> String {
+        let words = name.split('_').filter(|word| !word.is_empty());
+        match self {
+            RenameRule::Snake => words.collect::<Vec<_>>().join("_"),
+            RenameRule::Kebab => words.collect::<Vec<_>>().join("-"),
+            RenameRule::Camel => words
+                .enumerate()
+                .map(|(i, word)| {
+                    if i == 0 {
+                        word.to_owned()
+                    } else {
+                        capitalize(word)
+                    }
+                })
+                .collect(),
+            RenameRule::Pascal => words.map(capitalize).collect(),
+            RenameRule::ScreamingSnake => words.collect::<Vec<_>>().join("_").to_uppercase(),
+            RenameRule::ScreamingKebab => words.collect::<Vec<_>>().join("-").to_uppercase(),
+            RenameRule::Lower => words.collect(),
+            RenameRule::Upper => words.collect::<String>().to_uppercase(),
+        }
+    }
+}
+
+/// Converts the first character of `word` to uppercase.
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn apply_rename_rules_test() {
+        let cases = [
+            ("snake_case", "max_age"),
+            ("kebab-case", "max-age"),
+            ("camelCase", "maxAge"),
+            ("PascalCase", "MaxAge"),
+            ("SCREAMING_SNAKE_CASE", "MAX_AGE"),
+            ("SCREAMING-KEBAB-CASE", "MAX-AGE"),
+            ("lowercase", "maxage"),
+            ("UPPERCASE", "MAXAGE"),
+        ];
+
+        for (rule_name, expected) in &cases {
+            let rule = RenameRule::from_name(rule_name).unwrap();
+            assert_eq!(rule.apply("max_age"), *expected);
+        }
+    }
+
+    #[test]
+    fn unknown_rename_rule_test() {
+        assert_eq!(RenameRule::from_name("kebab_case"), None);
+    }
+}
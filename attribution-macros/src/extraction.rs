@@ -1,3 +1,9 @@
+use crate::options::ContainerOptions;
+use crate::options::FieldOptions;
+use std::collections::HashSet;
+use syn::ext::IdentExt;
+use syn::parse::Error;
+use syn::parse::Result;
 use syn::parse_quote;
 use syn::spanned::Spanned;
 use syn::Field;
@@ -7,47 +13,223 @@ use syn::Lit;
 use syn::LitInt;
 use syn::LitStr;
 use syn::Stmt;
+use syn::Type;
 
-pub fn build_extractors<'a>(fields: &'a Fields) -> impl Iterator<Item = Stmt> + 'a {
-    fields
-        .iter()
-        .enumerate()
-        .map(|(i, field)| build_extractor(i, field))
+/// Builds the statements which extract the value of every field from
+/// `attr_args`. The errors of every field are reported together, after which
+/// each field's value is bound to a variable named after the field.
+pub fn build_extractors(fields: &Fields, container: &ContainerOptions) -> Result<Vec<Stmt>> {
+    let mut has_rest = false;
+    for (i, field) in fields.iter().enumerate() {
+        if FieldOptions::from_attrs(&field.attrs)?.rest {
+            if has_rest {
+                let message = "Only one field can collect the remaining positional parameters.";
+                return Err(Error::new_spanned(field, message));
+            }
+
+            if let Fields::Unnamed(_) = fields {
+                if i + 1 != fields.len() {
+                    let message = "The field collecting the remaining positional parameters must be the last field.";
+                    return Err(Error::new_spanned(field, message));
+                }
+            }
+
+            has_rest = true;
+        }
+    }
+
+    let mut stmts: Vec<Stmt> = vec![parse_quote! {
+        let mut attr_errors: Vec<syn::parse::Error> = Vec::new();
+    }];
+
+    // Parameters supplied under an alias are renamed before any extraction
+    let mut known = vec![];
+    let mut names = HashSet::new();
+    for field in fields {
+        let options = FieldOptions::from_attrs(&field.attrs)?;
+        let name = match build_param_name(field, &options, container)? {
+            Some(name) => name,
+            None => continue,
+        };
+
+        for alias in options.aliases.iter().chain(Some(&name)) {
+            if !names.insert(alias.clone()) {
+                let message = format!("The parameter name '{}' is used more than once.", alias);
+                return Err(Error::new_spanned(field, message));
+            }
+        }
+
+        for alias in &options.aliases {
+            stmts.push(parse_quote! {
+                attr_args.alias(&#alias.into(), &#name.into());
+            });
+        }
+
+        known.push(name);
+    }
+
+    for (i, field) in fields.iter().enumerate() {
+        stmts.push(build_extractor(i, field, container)?);
+    }
+
+    // Report the problems with every field at once
+    stmts.push(parse_quote! {
+        if let Err(err) = attr_args.deny_positional() {
+            attr_errors.push(err);
+        }
+    });
+
+    if !container.allow_unknown {
+        stmts.push(parse_quote! {
+            if let Err(err) = attr_args.deny_unknown(&[#(#known),*]) {
+                attr_errors.push(err);
+            }
+        });
+    }
+
+    stmts.push(parse_quote! {
+        let mut attr_errors = attr_errors.into_iter();
+    });
+    stmts.push(parse_quote! {
+        if let Some(mut err) = attr_errors.next() {
+            err.extend(attr_errors);
+            return Err(err);
+        }
+    });
+
+    // Every extraction succeeded so each of the values is present
+    for (i, field) in fields.iter().enumerate() {
+        let (ident, _) = build_field_ident_and_key(i, field, container)?;
+        stmts.push(parse_quote! {
+            let #ident = #ident.unwrap();
+        });
+    }
+
+    Ok(stmts)
 }
 
-fn build_extractor(position: usize, field: &Field) -> Stmt {
+/// Builds the statement which extracts the value of a single field. A failed
+/// extraction is recorded in `attr_errors` and the value is `None`.
+fn build_extractor(position: usize, field: &Field, container: &ContainerOptions) -> Result<Stmt> {
     let field_span = field.span();
+    let options = FieldOptions::from_attrs(&field.attrs)?;
+    let (ident, field_key) = build_field_ident_and_key(position, field, container)?;
 
-    if let Some(ident) = &field.ident {
-        let lit_str = LitStr::new(&ident.to_string(), field_span);
-        let field_key = Lit::Str(lit_str);
-
-        parse_quote! {
-            let #ident = attribution::FromParameters::from_parameters(&mut attr_args, &#field_key.into())?;
+    if options.rest {
+        // Named fields don't occupy a position so they collect every positional parameter
+        let start = if field.ident.is_some() { 0 } else { position };
+        let start = LitInt::new(&format!("{}usize", start), field_span);
+        Ok(parse_quote! {
+            let #ident = attribution::FromRestParameters::from_rest_parameters(&mut attr_args, #start)
+                .map_err(|err| attr_errors.push(err.into()))
+                .ok();
+        })
+    } else if let Some(default) = &options.default {
+        let ty = &field.ty;
+        if is_flag(ty) {
+            let message = "A `Flag` is already unset when its parameter is absent so it cannot have a default.";
+            return Err(Error::new_spanned(ty, message));
         }
+
+        let default = default.to_expr();
+        Ok(parse_quote! {
+            let #ident = <Option<#ty> as attribution::FromParameters>::from_parameters(&mut attr_args, &#field_key.into())
+                .map(|val| val.unwrap_or_else(|| #default))
+                .map_err(|err| attr_errors.push(err.into()))
+                .ok();
+        })
+    } else if options.repeated {
+        Ok(parse_quote! {
+            let #ident = attribution::FromRepeatedParameters::from_repeated_parameters(&mut attr_args, &#field_key.into())
+                .map_err(|err| attr_errors.push(err.into()))
+                .ok();
+        })
     } else {
-        let ident_name = format!("_{}", position);
-        let ident = Ident::new(&ident_name, field_span);
+        Ok(parse_quote! {
+            let #ident = attribution::FromParameters::from_parameters(&mut attr_args, &#field_key.into())
+                .map_err(|err| attr_errors.push(err.into()))
+                .ok();
+        })
+    }
+}
 
-        let lit_int = LitInt::new(&format!("{}usize", position), field_span);
-        let field_key = Lit::Int(lit_int);
-        parse_quote! {
-            let #ident = attribution::FromParameters::from_parameters(&mut attr_args, &#field_key.into())?;
+/// Checks if the type of a field is `attribution::Flag`.
+fn is_flag(ty: &Type) -> bool {
+    if let Type::Path(type_path) = ty {
+        if let Some(segment) = type_path.path.segments.last() {
+            return type_path.qself.is_none() && segment.ident == "Flag";
+        }
+    }
+
+    false
+}
+
+/// Builds the name of the variable holding the field's value and the key of
+/// the parameter the field is extracted from.
+fn build_field_ident_and_key(
+    position: usize,
+    field: &Field,
+    container: &ContainerOptions,
+) -> Result<(Ident, Lit)> {
+    let field_span = field.span();
+    let options = FieldOptions::from_attrs(&field.attrs)?;
+    match (&field.ident, build_param_name(field, &options, container)?) {
+        (Some(ident), Some(name)) => {
+            let lit_str = LitStr::new(&name, field_span);
+            Ok((ident.clone(), Lit::Str(lit_str)))
+        }
+        _ => {
+            let ident_name = format!("_{}", position);
+            let ident = Ident::new(&ident_name, field_span);
+
+            let lit_int = LitInt::new(&format!("{}usize", position), field_span);
+            Ok((ident, Lit::Int(lit_int)))
         }
     }
 }
 
+/// Builds the name of the parameter a named field is extracted from. The name
+/// is the field's `rename` option, otherwise the container's `rename_all` rule
+/// applied to the name of the field. Unnamed fields have no name.
+fn build_param_name(
+    field: &Field,
+    options: &FieldOptions,
+    container: &ContainerOptions,
+) -> Result<Option<String>> {
+    let ident = match &field.ident {
+        Some(ident) => ident,
+        None if options.rename.is_some() || !options.aliases.is_empty() => {
+            let message = "The `rename` and `alias` options can only be applied to named fields.";
+            return Err(Error::new_spanned(field, message));
+        }
+        None => return Ok(None),
+    };
+
+    let name = ident.unraw().to_string();
+    let name = match (&options.rename, container.rename_all) {
+        (Some(rename), _) => rename.clone(),
+        (None, Some(rule)) => rule.apply(&name),
+        (None, None) => name,
+    };
+
+    Ok(Some(name))
+}
+
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use crate::casing::RenameRule;
     use pretty_assertions::assert_eq;
     use proc_macro2::Span;
     use syn::parse_quote;
     use syn::Field;
 
     fn build_test_field(use_name: bool) -> Field {
-        let attrs = vec![];
+        build_test_field_with_attrs(use_name, vec![])
+    }
+
+    fn build_test_field_with_attrs(use_name: bool, attrs: Vec<syn::Attribute>) -> Field {
         let vis = syn::Visibility::Inherited;
         let ident = if use_name {
             Some(parse_quote!(foo))
@@ -72,9 +254,11 @@ mod tests {
     fn build_named_field_extractor_test() {
         let raw_field = build_test_field(true);
 
-        let actual = build_extractor(0, &raw_field);
+        let actual = build_extractor(0, &raw_field, &ContainerOptions::default()).unwrap();
         let expected: Stmt = parse_quote! {
-            let foo = attribution::FromParameters::from_parameters(&mut attr_args, &"foo".into())?;
+            let foo = attribution::FromParameters::from_parameters(&mut attr_args, &"foo".into())
+                .map_err(|err| attr_errors.push(err.into()))
+                .ok();
         };
 
         assert_eq!(actual, expected);
@@ -84,11 +268,170 @@ mod tests {
     fn build_unnamed_field_extractor_test() {
         let raw_field = build_test_field(false);
 
-        let actual = build_extractor(0, &raw_field);
+        let actual = build_extractor(0, &raw_field, &ContainerOptions::default()).unwrap();
         let expected: Stmt = parse_quote! {
-            let _0 = attribution::FromParameters::from_parameters(&mut attr_args, &0usize.into())?;
+            let _0 = attribution::FromParameters::from_parameters(&mut attr_args, &0usize.into())
+                .map_err(|err| attr_errors.push(err.into()))
+                .ok();
         };
 
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn build_repeated_field_extractor_test() {
+        let raw_field =
+            build_test_field_with_attrs(true, vec![parse_quote!(#[attr_args(repeated)])]);
+
+        let actual = build_extractor(0, &raw_field, &ContainerOptions::default()).unwrap();
+        let expected: Stmt = parse_quote! {
+            let foo = attribution::FromRepeatedParameters::from_repeated_parameters(&mut attr_args, &"foo".into())
+                .map_err(|err| attr_errors.push(err.into()))
+                .ok();
+        };
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn build_rest_field_extractor_test() {
+        let raw_field = build_test_field_with_attrs(false, vec![parse_quote!(#[attr_args(rest)])]);
+
+        let actual = build_extractor(1, &raw_field, &ContainerOptions::default()).unwrap();
+        let expected: Stmt = parse_quote! {
+            let _1 = attribution::FromRestParameters::from_rest_parameters(&mut attr_args, 1usize)
+                .map_err(|err| attr_errors.push(err.into()))
+                .ok();
+        };
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn build_default_field_extractor_test() {
+        let raw_field =
+            build_test_field_with_attrs(true, vec![parse_quote!(#[attr_args(default)])]);
+
+        let actual = build_extractor(0, &raw_field, &ContainerOptions::default()).unwrap();
+        let expected: Stmt = parse_quote! {
+            let foo = <Option<bool> as attribution::FromParameters>::from_parameters(&mut attr_args, &"foo".into())
+                .map(|val| val.unwrap_or_else(|| core::default::Default::default()))
+                .map_err(|err| attr_errors.push(err.into()))
+                .ok();
+        };
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn build_default_flag_extractor_test() {
+        let mut raw_field =
+            build_test_field_with_attrs(true, vec![parse_quote!(#[attr_args(default)])]);
+        raw_field.ty = parse_quote!(attribution::Flag);
+
+        let err = build_extractor(0, &raw_field, &ContainerOptions::default()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "A `Flag` is already unset when its parameter is absent so it cannot have a default."
+        );
+    }
+
+    #[test]
+    fn build_extractors_rest_not_last_test() {
+        let fields: syn::FieldsUnnamed = parse_quote!((#[attr_args(rest)] Vec<String>, bool));
+
+        assert!(build_extractors(&Fields::Unnamed(fields), &ContainerOptions::default()).is_err());
+    }
+
+    #[test]
+    fn build_raw_field_extractor_test() {
+        let mut raw_field = build_test_field(true);
+        raw_field.ident = Some(parse_quote!(r#type));
+
+        let actual = build_extractor(0, &raw_field, &ContainerOptions::default()).unwrap();
+        let expected: Stmt = parse_quote! {
+            let r#type = attribution::FromParameters::from_parameters(&mut attr_args, &"type".into())
+                .map_err(|err| attr_errors.push(err.into()))
+                .ok();
+        };
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn build_renamed_field_extractor_test() {
+        let raw_field =
+            build_test_field_with_attrs(true, vec![parse_quote!(#[attr_args(rename = "max-age")])]);
+
+        let actual = build_extractor(0, &raw_field, &ContainerOptions::default()).unwrap();
+        let expected: Stmt = parse_quote! {
+            let foo = attribution::FromParameters::from_parameters(&mut attr_args, &"max-age".into())
+                .map_err(|err| attr_errors.push(err.into()))
+                .ok();
+        };
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn build_rename_all_field_extractor_test() {
+        let mut raw_field = build_test_field(true);
+        raw_field.ident = Some(parse_quote!(max_age));
+        let container = ContainerOptions {
+            rename_all: Some(RenameRule::Kebab),
+            ..Default::default()
+        };
+
+        let actual = build_extractor(0, &raw_field, &container).unwrap();
+        let expected: Stmt = parse_quote! {
+            let max_age = attribution::FromParameters::from_parameters(&mut attr_args, &"max-age".into())
+                .map_err(|err| attr_errors.push(err.into()))
+                .ok();
+        };
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn build_aliased_field_extractors_test() {
+        let fields: syn::FieldsNamed = parse_quote!({
+            #[attr_args(alias = "colour")]
+            color: String
+        });
+
+        let actual =
+            build_extractors(&Fields::Named(fields), &ContainerOptions::default()).unwrap();
+        let expected_alias: Stmt = parse_quote! {
+            attr_args.alias(&"colour".into(), &"color".into());
+        };
+        let expected_known: Stmt = parse_quote! {
+            if let Err(err) = attr_args.deny_unknown(&["color"]) {
+                attr_errors.push(err);
+            }
+        };
+
+        assert_eq!(actual[1], expected_alias);
+        assert!(actual.contains(&expected_known));
+    }
+
+    #[test]
+    fn build_extractors_duplicate_name_test() {
+        let fields: syn::FieldsNamed = parse_quote!({
+            #[attr_args(rename = "size")]
+            width: u32,
+            size: u32
+        });
+
+        assert!(build_extractors(&Fields::Named(fields), &ContainerOptions::default()).is_err());
+    }
+
+    #[test]
+    fn build_extractors_unnamed_rename_test() {
+        let fields: syn::FieldsUnnamed = parse_quote!(
+            (#[attr_args(rename = "size")]
+            u32)
+        );
+
+        assert!(build_extractors(&Fields::Unnamed(fields), &ContainerOptions::default()).is_err());
+    }
 }
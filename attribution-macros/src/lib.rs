@@ -2,17 +2,21 @@
 
 extern crate proc_macro;
 
+mod casing;
 mod construction;
 mod extraction;
 mod identifiers;
+mod options;
 
 use self::construction::build_struct_constructor;
 use self::construction::build_variant_constructor;
 use self::extraction::build_extractors;
 use self::identifiers::build_variant_parser_ident;
 use self::identifiers::build_variant_parser_idents;
+use self::options::ContainerOptions;
 use proc_macro::TokenStream;
 use quote::quote;
+use syn::parse::Result;
 use syn::parse_macro_input;
 use syn::parse_quote;
 use syn::Ident;
@@ -25,95 +29,166 @@ use syn::Variant;
 
 /// The derive macro that is used to generate the parsing logic for a struct
 /// representing the parameters for an attribute.
-#[proc_macro_derive(AttrArgs)]
+#[proc_macro_derive(AttrArgs, attributes(attr_args))]
 pub fn attr_args(input: TokenStream) -> TokenStream {
-    let output = match parse_macro_input!(input as Item) {
-        Item::Struct(input_struct) => {
-            let impl_item = impl_parse_for_struct(&input_struct);
-            quote! { #impl_item }
-        }
-        Item::Enum(input_enum) => {
-            let impl_item = impl_parse_for_enum(&input_enum);
-            quote! { #impl_item }
-        }
+    let input = parse_macro_input!(input as Item);
+    let (name, try_from_item) = match &input {
+        Item::Struct(input_struct) => (
+            &input_struct.ident,
+            impl_try_from_params_for_struct(input_struct),
+        ),
+        Item::Enum(input_enum) => (&input_enum.ident, impl_try_from_params_for_enum(input_enum)),
         _ => panic!("The attribute can only be applied to structs and enums"),
     };
 
+    // The remaining impls are still emitted alongside an error so that the
+    // type's uses don't report unrelated missing impls
+    let try_from_item = match try_from_item {
+        Ok(try_from_item) => quote!(#try_from_item),
+        Err(err) => {
+            let error = err.to_compile_error();
+            let fallback_item = impl_try_from_params_fallback(name);
+            quote! {
+                #error
+                #fallback_item
+            }
+        }
+    };
+
+    let parse_item = impl_parse(name);
+    let try_from_val_item = impl_try_from_param_val(name);
+
+    let output = quote! {
+        #try_from_item
+        #parse_item
+        #try_from_val_item
+    };
+
     output.into()
 }
 
+/// Creates the `Parse` impl which parses the `Parameters` of the attribute
+/// and then converts them into the tagged type.
+fn impl_parse(name: &Ident) -> ItemImpl {
+    parse_quote! {
+        impl syn::parse::Parse for #name {
+            fn parse(buffer: &syn::parse::ParseBuffer) -> syn::parse::Result<Self> {
+                let attr_args = <attribution::Parameters as syn::parse::Parse>::parse(buffer)?;
+                <Self as core::convert::TryFrom<attribution::Parameters>>::try_from(attr_args)
+            }
+        }
+    }
+}
+
+/// Creates the impl which allows the tagged type to be used as the type of
+/// a field in another type, where it is parsed from a nested parameter list.
+fn impl_try_from_param_val(name: &Ident) -> ItemImpl {
+    parse_quote! {
+        impl core::convert::TryFrom<attribution::ParamVal> for #name {
+            type Error = attribution::TryIntoParamValError;
+
+            fn try_from(param_val: attribution::ParamVal) -> core::result::Result<Self, Self::Error> {
+                let attr_args = <attribution::Parameters as core::convert::TryFrom<attribution::ParamVal>>::try_from(param_val)?;
+                <Self as core::convert::TryFrom<attribution::Parameters>>::try_from(attr_args)
+                    .map_err(attribution::TryIntoParamValError::from)
+            }
+        }
+    }
+}
+
+/// Creates the impl which stands in for the conversion from `Parameters` when
+/// the tagged type's options are invalid.
+fn impl_try_from_params_fallback(name: &Ident) -> ItemImpl {
+    parse_quote! {
+        impl core::convert::TryFrom<attribution::Parameters> for #name {
+            type Error = syn::parse::Error;
+
+            fn try_from(_: attribution::Parameters) -> syn::parse::Result<Self> {
+                core::unreachable!()
+            }
+        }
+    }
+}
+
 /// Creates the impl body for a tagged struct
-fn impl_parse_for_struct(input_struct: &ItemStruct) -> ItemImpl {
+fn impl_try_from_params_for_struct(input_struct: &ItemStruct) -> Result<ItemImpl> {
     let struct_name = &input_struct.ident;
 
     // Build the statements that pull out the field values from the Parameters
-    let field_extractors = build_extractors(&input_struct.fields);
+    let container = ContainerOptions::from_attrs(&input_struct.attrs)?;
+    let field_extractors = build_extractors(&input_struct.fields, &container)?;
 
     // Build the statement that constructs the struct
-    let struct_return = build_struct_constructor(&input_struct);
+    let struct_return = build_struct_constructor(input_struct);
 
-    parse_quote! {
-        impl syn::parse::Parse for #struct_name {
-            fn parse(buffer: &syn::parse::ParseBuffer) -> syn::parse::Result<Self> {
-                let mut attr_args = <attribution::Parameters as syn::parse::Parse>::parse(buffer)?;
+    Ok(parse_quote! {
+        impl core::convert::TryFrom<attribution::Parameters> for #struct_name {
+            type Error = syn::parse::Error;
 
+            fn try_from(mut attr_args: attribution::Parameters) -> syn::parse::Result<Self> {
                 #(#field_extractors)*
 
                 #struct_return
             }
         }
-    }
+    })
 }
 
 /// Creates the impl body for a tagged enum
-fn impl_parse_for_enum(input_enum: &ItemEnum) -> ItemImpl {
+fn impl_try_from_params_for_enum(input_enum: &ItemEnum) -> Result<ItemImpl> {
     let enum_name = &input_enum.ident;
-    let parser_idents = build_variant_parser_idents(&input_enum);
+    let parser_idents = build_variant_parser_idents(input_enum);
 
     // Builds a function used to try to parse each variant of the enum
+    let container = ContainerOptions::from_attrs(&input_enum.attrs)?;
     let parser_decls = input_enum
         .variants
         .iter()
-        .map(|variant| build_variant_parser(&input_enum.ident, variant));
+        .map(|variant| build_variant_parser(&input_enum.ident, variant, &container))
+        .collect::<Result<Vec<_>>>()?;
 
-    parse_quote! {
+    Ok(parse_quote! {
+        impl core::convert::TryFrom<attribution::Parameters> for #enum_name {
+            type Error = syn::parse::Error;
 
-        impl syn::parse::Parse for #enum_name {
-            fn parse(buffer: &syn::parse::ParseBuffer) -> syn::parse::Result<Self> {
+            fn try_from(attr_args: attribution::Parameters) -> syn::parse::Result<Self> {
                 #(#parser_decls)*
 
+                // The errors of every variant are reported when none of them match
+                let mut attr_errors = syn::parse::Error::new(attr_args.span(), "No matching variant found.");
                 #(
-                    if let Ok(val) = buffer.call(#parser_idents) {
-                        return Ok(val);
+                    match #parser_idents(attr_args.clone()) {
+                        Ok(val) => return Ok(val),
+                        Err(err) => attr_errors.combine(err),
                     }
                 )*
 
-                else {
-                    return Err(buffer.error("No matching variant found."));
-                }
+                Err(attr_errors)
             }
         }
-    }
+    })
 }
 
-/// Constructs a function that will attempt to parse a token stream as a
+/// Constructs a function that will attempt to convert the parameters into a
 /// provided enum variant.
-fn build_variant_parser(enum_name: &Ident, variant: &Variant) -> ItemFn {
+fn build_variant_parser(
+    enum_name: &Ident,
+    variant: &Variant,
+    container: &ContainerOptions,
+) -> Result<ItemFn> {
     let parser_ident = build_variant_parser_ident(variant);
 
-    let extractors = build_extractors(&variant.fields);
+    let extractors = build_extractors(&variant.fields, container)?;
     let constructor = build_variant_constructor(enum_name, variant);
 
-    parse_quote! {
+    Ok(parse_quote! {
         #[allow(non_snake_case)]
-        fn #parser_ident(buffer: &syn::parse::ParseBuffer) -> syn::parse::Result<#enum_name> {
-            let mut attr_args = buffer.parse::<attribution::Parameters>()?;
-
+        fn #parser_ident(mut attr_args: attribution::Parameters) -> syn::parse::Result<#enum_name> {
             #(#extractors)*
 
             #constructor
         }
-    }
+    })
 }
 
 #[cfg(test)]
@@ -124,19 +199,37 @@ mod tests {
     use syn::parse_quote;
 
     #[test]
-    fn impl_parse_for_struct_unnamed_struct_test() {
+    fn impl_try_from_params_for_struct_unnamed_struct_test() {
         let input_struct: ItemStruct = parse_quote! {
             struct Foo(u64, u64);
         };
 
-        let actual = impl_parse_for_struct(&input_struct);
+        let actual = impl_try_from_params_for_struct(&input_struct).unwrap();
         let expected: ItemImpl = parse_quote! {
-            impl syn::parse::Parse for Foo {
-                fn parse(buffer: &syn::parse::ParseBuffer) -> syn::parse::Result<Self> {
-                    let mut attr_args = <attribution::Parameters as syn::parse::Parse>::parse(buffer)?;
+            impl core::convert::TryFrom<attribution::Parameters> for Foo {
+                type Error = syn::parse::Error;
 
-                    let _0 = attribution::FromParameters::from_parameters(&mut attr_args, &0usize.into())?;
-                    let _1 = attribution::FromParameters::from_parameters(&mut attr_args, &1usize.into())?;
+                fn try_from(mut attr_args: attribution::Parameters) -> syn::parse::Result<Self> {
+                    let mut attr_errors: Vec<syn::parse::Error> = Vec::new();
+                    let _0 = attribution::FromParameters::from_parameters(&mut attr_args, &0usize.into())
+                        .map_err(|err| attr_errors.push(err.into()))
+                        .ok();
+                    let _1 = attribution::FromParameters::from_parameters(&mut attr_args, &1usize.into())
+                        .map_err(|err| attr_errors.push(err.into()))
+                        .ok();
+                    if let Err(err) = attr_args.deny_positional() {
+                        attr_errors.push(err);
+                    }
+                    if let Err(err) = attr_args.deny_unknown(&[]) {
+                        attr_errors.push(err);
+                    }
+                    let mut attr_errors = attr_errors.into_iter();
+                    if let Some(mut err) = attr_errors.next() {
+                        err.extend(attr_errors);
+                        return Err(err);
+                    }
+                    let _0 = _0.unwrap();
+                    let _1 = _1.unwrap();
 
                     Ok(Foo(_0, _1))
                 }
@@ -145,4 +238,24 @@ mod tests {
 
         assert_eq!(expected, actual);
     }
+
+    #[test]
+    fn impl_try_from_param_val_test() {
+        let name: Ident = parse_quote!(Foo);
+
+        let actual = impl_try_from_param_val(&name);
+        let expected: ItemImpl = parse_quote! {
+            impl core::convert::TryFrom<attribution::ParamVal> for Foo {
+                type Error = attribution::TryIntoParamValError;
+
+                fn try_from(param_val: attribution::ParamVal) -> core::result::Result<Self, Self::Error> {
+                    let attr_args = <attribution::Parameters as core::convert::TryFrom<attribution::ParamVal>>::try_from(param_val)?;
+                    <Self as core::convert::TryFrom<attribution::Parameters>>::try_from(attr_args)
+                        .map_err(attribution::TryIntoParamValError::from)
+                }
+            }
+        };
+
+        assert_eq!(expected, actual);
+    }
 }
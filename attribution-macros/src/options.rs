@@ -0,0 +1,372 @@
+use crate::casing::RenameRule;
+use quote::ToTokens;
+use syn::ext::IdentExt;
+use syn::parse::Error;
+use syn::parse::Parser;
+use syn::parse::Result;
+use syn::parse_quote;
+use syn::Attribute;
+use syn::Expr;
+use syn::Ident;
+use syn::Lit;
+use syn::LitStr;
+use syn::Meta;
+use syn::MetaNameValue;
+use syn::NestedMeta;
+use syn::Path;
+
+/// The name of the helper attribute used to configure the derived parsing
+/// logic (e.g. `#[attr_args(repeated)]`).
+const ATTR_NAME: &str = "attr_args";
+
+/// The options that can be applied to a field through the `attr_args`
+/// helper attribute.
+#[derive(Debug, Default, PartialEq)]
+pub struct FieldOptions {
+    /// Every occurrence of the parameter is collected instead of it being an
+    /// error for the parameter to be supplied more than once.
+    pub repeated: bool,
+
+    /// Every remaining positional parameter is collected into the field.
+    pub rest: bool,
+
+    /// The value used for the field when its parameter is not supplied.
+    pub default: Option<FieldDefault>,
+
+    /// The name of the parameter the field is extracted from, in place of the
+    /// name of the field (e.g. `#[attr_args(rename = "max-age")]`).
+    pub rename: Option<String>,
+
+    /// Additional names the parameter can be supplied with (e.g.
+    /// `#[attr_args(alias = "colour")]`).
+    pub aliases: Vec<String>,
+}
+
+/// The value of a field whose parameter is not supplied.
+#[derive(Debug, PartialEq)]
+pub enum FieldDefault {
+    /// The value is `Default::default()` (e.g. `#[attr_args(default)]`).
+    Trait,
+
+    /// The value is the result of an expression (e.g.
+    /// `#[attr_args(default = "Speed::Slow")]`).
+    Expr(Box<Expr>),
+
+    /// The value is the result of calling a function (e.g.
+    /// `#[attr_args(default_with = "my::func")]`).
+    Fn(Path),
+}
+
+impl FieldDefault {
+    /// Builds the expression which produces the default value.
+    pub fn to_expr(&self) -> Expr {
+        match self {
+            FieldDefault::Trait => parse_quote!(core::default::Default::default()),
+            FieldDefault::Expr(expr) => expr.as_ref().clone(),
+            FieldDefault::Fn(path) => parse_quote!(#path()),
+        }
+    }
+}
+
+impl FieldOptions {
+    pub fn from_attrs(attrs: &[Attribute]) -> Result<Self> {
+        let mut options = FieldOptions::default();
+
+        // The options which conflict with others are kept so that errors can
+        // point at them
+        let mut rest_meta = None;
+        let mut default_meta = None;
+        for meta in parse_options(attrs)? {
+            match &meta {
+                Meta::Path(path) if path.is_ident("repeated") => options.repeated = true,
+                Meta::Path(path) if path.is_ident("rest") => {
+                    options.rest = true;
+                    rest_meta = Some(meta.clone());
+                }
+                Meta::Path(path) if path.is_ident("default") => {
+                    options.default = Some(FieldDefault::Trait);
+                    default_meta = Some(meta.clone());
+                }
+                Meta::NameValue(name_value) if name_value.path.is_ident("default") => {
+                    let expr = match &name_value.lit {
+                        Lit::Str(lit_str) => lit_str.parse()?,
+                        lit => parse_quote!(#lit),
+                    };
+                    options.default = Some(FieldDefault::Expr(Box::new(expr)));
+                    default_meta = Some(meta.clone());
+                }
+                Meta::NameValue(name_value) if name_value.path.is_ident("default_with") => {
+                    let path = expect_lit_str(name_value)?.parse()?;
+                    options.default = Some(FieldDefault::Fn(path));
+                    default_meta = Some(meta.clone());
+                }
+                Meta::NameValue(name_value) if name_value.path.is_ident("rename") => {
+                    options.rename = Some(parse_param_name(name_value)?);
+                }
+                Meta::NameValue(name_value) if name_value.path.is_ident("alias") => {
+                    options.aliases.push(parse_param_name(name_value)?);
+                }
+                other => return Err(Error::new_spanned(other, "Unrecognized field option.")),
+            }
+        }
+
+        if let (true, Some(rest_meta)) = (options.repeated, &rest_meta) {
+            let message = "The `repeated` and `rest` options cannot be combined.";
+            return Err(Error::new_spanned(rest_meta, message));
+        }
+
+        if let (true, Some(default_meta)) = (options.repeated || options.rest, &default_meta) {
+            let message = format!(
+                "The `{}` option cannot be combined with `repeated` or `rest`.",
+                default_meta.path().to_token_stream()
+            );
+            return Err(Error::new_spanned(default_meta, message));
+        }
+
+        Ok(options)
+    }
+}
+
+/// The options that can be applied to a struct or enum through the `attr_args`
+/// helper attribute.
+#[derive(Debug, Default, PartialEq)]
+pub struct ContainerOptions {
+    /// Named parameters which do not correspond to a field are ignored instead
+    /// of being an error.
+    pub allow_unknown: bool,
+
+    /// The case convention applied to the name of every field which is not
+    /// renamed (e.g. `#[attr_args(rename_all = "kebab-case")]`).
+    pub rename_all: Option<RenameRule>,
+}
+
+impl ContainerOptions {
+    pub fn from_attrs(attrs: &[Attribute]) -> Result<Self> {
+        let mut options = ContainerOptions::default();
+        for meta in parse_options(attrs)? {
+            match &meta {
+                Meta::Path(path) if path.is_ident("allow_unknown") => options.allow_unknown = true,
+                Meta::NameValue(name_value) if name_value.path.is_ident("rename_all") => {
+                    let lit_str = expect_lit_str(name_value)?;
+                    let rule = RenameRule::from_name(&lit_str.value()).ok_or_else(|| {
+                        let message = format!("Expected one of: {}.", RenameRule::names());
+                        Error::new_spanned(lit_str, message)
+                    })?;
+                    options.rename_all = Some(rule);
+                }
+                other => return Err(Error::new_spanned(other, "Unrecognized container option.")),
+            }
+        }
+
+        Ok(options)
+    }
+}
+
+/// Gets the string literal that is the value of an option.
+fn expect_lit_str(name_value: &MetaNameValue) -> Result<&LitStr> {
+    match &name_value.lit {
+        Lit::Str(lit_str) => Ok(lit_str),
+        other => Err(Error::new_spanned(other, "Expected a string literal.")),
+    }
+}
+
+/// Parses the value of an option which names a parameter. The name must be
+/// one or more identifiers joined by hyphens (e.g. `max-age`).
+fn parse_param_name(name_value: &MetaNameValue) -> Result<String> {
+    let lit_str = expect_lit_str(name_value)?;
+    let name = lit_str.value();
+    let is_valid = name
+        .split('-')
+        .all(|segment| Ident::parse_any.parse_str(segment).is_ok());
+
+    if is_valid {
+        Ok(name)
+    } else {
+        let message = "Expected a parameter name made of identifiers joined by hyphens.";
+        Err(Error::new_spanned(lit_str, message))
+    }
+}
+
+/// Collects the options contained in every `attr_args` attribute.
+fn parse_options(attrs: &[Attribute]) -> Result<Vec<Meta>> {
+    let mut options = vec![];
+    for attr in attrs.iter().filter(|attr| attr.path.is_ident(ATTR_NAME)) {
+        match attr.parse_meta()? {
+            Meta::List(list) => {
+                for nested in list.nested {
+                    match nested {
+                        NestedMeta::Meta(meta) => options.push(meta),
+                        NestedMeta::Lit(lit) => {
+                            return Err(Error::new_spanned(lit, "Expected an option name."))
+                        }
+                    }
+                }
+            }
+            other => {
+                let message = format!("Expected a list of options (e.g. `#[{}(...)]`).", ATTR_NAME);
+                return Err(Error::new_spanned(other, message));
+            }
+        }
+    }
+
+    Ok(options)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use syn::parse_quote;
+    use syn::Field;
+    use syn::FieldsNamed;
+
+    fn first_field(fields: FieldsNamed) -> Field {
+        fields.named.into_iter().next().unwrap()
+    }
+
+    #[test]
+    fn repeated_field_options_test() {
+        let field = first_field(parse_quote!({
+            #[attr_args(repeated)]
+            paths: Vec<String>
+        }));
+
+        let actual = FieldOptions::from_attrs(&field.attrs).unwrap();
+        let expected = FieldOptions {
+            repeated: true,
+            ..Default::default()
+        };
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn default_field_options_test() {
+        let field = first_field(parse_quote!({
+            #[attr_args(default)]
+            enabled: bool
+        }));
+        let actual = FieldOptions::from_attrs(&field.attrs).unwrap();
+        assert_eq!(actual.default, Some(FieldDefault::Trait));
+
+        let field = first_field(parse_quote!({
+            #[attr_args(default_with = "my::func")]
+            name: String
+        }));
+        let actual = FieldOptions::from_attrs(&field.attrs).unwrap();
+        let expected: Expr = parse_quote!(my::func());
+        assert_eq!(actual.default.unwrap().to_expr(), expected);
+
+        let field = first_field(parse_quote!({
+            #[attr_args(default = "Speed::Slow")]
+            speed: Speed
+        }));
+        let actual = FieldOptions::from_attrs(&field.attrs).unwrap();
+        let expected: Expr = parse_quote!(Speed::Slow);
+        assert_eq!(actual.default.unwrap().to_expr(), expected);
+
+        let field = first_field(parse_quote!({
+            #[attr_args(default = 10)]
+            retries: u8
+        }));
+        let actual = FieldOptions::from_attrs(&field.attrs).unwrap();
+        let expected: Expr = parse_quote!(10);
+        assert_eq!(actual.default.unwrap().to_expr(), expected);
+    }
+
+    #[test]
+    fn conflicting_field_options_test() {
+        let field = first_field(parse_quote!({
+            /// The paths
+            #[attr_args(repeated)]
+            #[attr_args(rest)]
+            paths: Vec<String>
+        }));
+
+        let err = FieldOptions::from_attrs(&field.attrs).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "The `repeated` and `rest` options cannot be combined."
+        );
+    }
+
+    #[test]
+    fn unknown_field_options_test() {
+        let field = first_field(parse_quote!({
+            #[attr_args(unknown)]
+            paths: Vec<String>
+        }));
+
+        assert!(FieldOptions::from_attrs(&field.attrs).is_err());
+    }
+
+    #[test]
+    fn allow_unknown_container_options_test() {
+        let input_struct: syn::ItemStruct = parse_quote! {
+            #[attr_args(allow_unknown)]
+            struct Foo {}
+        };
+
+        let actual = ContainerOptions::from_attrs(&input_struct.attrs).unwrap();
+        let expected = ContainerOptions {
+            allow_unknown: true,
+            ..Default::default()
+        };
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn rename_field_options_test() {
+        let field = first_field(parse_quote!({
+            #[attr_args(rename = "max-age", alias = "maxAge", alias = "age")]
+            max_age: u32
+        }));
+
+        let actual = FieldOptions::from_attrs(&field.attrs).unwrap();
+        let expected = FieldOptions {
+            rename: Some("max-age".into()),
+            aliases: vec!["maxAge".into(), "age".into()],
+            ..Default::default()
+        };
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn invalid_rename_field_options_test() {
+        let field = first_field(parse_quote!({
+            #[attr_args(rename = "max age")]
+            max_age: u32
+        }));
+        assert!(FieldOptions::from_attrs(&field.attrs).is_err());
+
+        let field = first_field(parse_quote!({
+            #[attr_args(alias = 1)]
+            max_age: u32
+        }));
+        assert!(FieldOptions::from_attrs(&field.attrs).is_err());
+    }
+
+    #[test]
+    fn rename_all_container_options_test() {
+        let input_struct: syn::ItemStruct = parse_quote! {
+            #[attr_args(rename_all = "kebab-case")]
+            struct Foo {}
+        };
+
+        let actual = ContainerOptions::from_attrs(&input_struct.attrs).unwrap();
+        assert_eq!(actual.rename_all, Some(RenameRule::Kebab));
+
+        let input_struct: syn::ItemStruct = parse_quote! {
+            #[attr_args(rename_all = "kebab")]
+            struct Foo {}
+        };
+
+        let err = ContainerOptions::from_attrs(&input_struct.attrs).unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("Expected one of: \"snake_case\""));
+    }
+}
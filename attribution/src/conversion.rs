@@ -1,35 +1,165 @@
 use crate::params::ParamKey;
 use crate::params::ParamVal;
+use crate::params::TryIntoParamValError;
 use crate::Parameters;
 use core::convert::TryFrom;
-use core::convert::TryInto;
 use proc_macro2::Span as Span2;
+use std::error::Error;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::fmt::Result as FmtResult;
 use syn::parse::Error as ParseError;
+use syn::spanned::Spanned;
 
 /// An error that occurs as a result of a failed conversion of a `Parameters`
 /// struct
 #[derive(Debug)]
-pub enum FromParametersError<'a> {
+pub enum FromParametersError {
     /// Indicates the error ocurred because a value for a specified parameter
     /// was not supplied.
-    MissingParam { param_key: &'a ParamKey },
+    MissingParam { param_key: ParamKey, span: Span2 },
 
     /// Indicates the error occurred because the value that was attempted for conversion
     /// was for the incorrect type.
-    UnexpectedType,
+    UnexpectedType {
+        param_key: ParamKey,
+        expected: String,
+        found: &'static str,
+        span: Span2,
+    },
+
+    /// Indicates the error occurred because the value of a parameter was of the
+    /// correct type but could not be converted (e.g. the integer was too large).
+    InvalidValue {
+        param_key: ParamKey,
+        source: TryIntoParamValError,
+    },
+
+    /// Indicates the error occurred because a parameter that only accepts a
+    /// single value was supplied more than once.
+    DuplicateParam { first: ParamKey, repeat: ParamKey },
+
+    /// Indicates the error occurred while converting the contents of a nested
+    /// parameter list.
+    Nested(ParseError),
 }
 
-impl<'a> From<FromParametersError<'a>> for ParseError {
-    fn from(src: FromParametersError<'a>) -> ParseError {
-        match src {
-            FromParametersError::MissingParam { param_key } => {
-                let message = format!("The required parameter '{}' is missing.", param_key);
-                ParseError::new(Span2::call_site(), message)
+impl FromParametersError {
+    /// Creates the error for a value of the parameter `param_key` which could
+    /// not be converted.
+    pub fn invalid_value(param_key: &ParamKey, err: TryIntoParamValError) -> Self {
+        match err {
+            TryIntoParamValError::UnexpectedType {
+                span,
+                expected,
+                found,
+            } => FromParametersError::UnexpectedType {
+                param_key: param_key.clone(),
+                expected,
+                found,
+                span,
+            },
+            TryIntoParamValError::Nested(err) => FromParametersError::Nested(err),
+            source => FromParametersError::InvalidValue {
+                param_key: param_key.clone(),
+                source,
+            },
+        }
+    }
+
+    /// The location of the problem.
+    pub fn span(&self) -> Span2 {
+        match self {
+            FromParametersError::MissingParam { span, .. } => *span,
+            FromParametersError::UnexpectedType { span, .. } => *span,
+            FromParametersError::InvalidValue { source, .. } => source.span(),
+            FromParametersError::DuplicateParam { repeat, .. } => repeat.span(),
+            FromParametersError::Nested(err) => err.span(),
+        }
+    }
+}
+
+impl Display for FromParametersError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            FromParametersError::MissingParam { param_key, .. } => match param_key {
+                ParamKey::Named(..) => {
+                    write!(f, "The required parameter '{}' is missing.", param_key)
+                }
+                ParamKey::Unnamed(..) => write!(
+                    f,
+                    "The required positional parameter at index {} is missing.",
+                    param_key
+                ),
+            },
+            FromParametersError::UnexpectedType {
+                param_key,
+                expected,
+                found,
+                ..
+            } => {
+                write_param(f, param_key)?;
+                write!(
+                    f,
+                    " expects a value of type `{}` but found {}.",
+                    expected, found
+                )
+            }
+            FromParametersError::InvalidValue { param_key, source } => {
+                write_param(f, param_key)?;
+                match source {
+                    TryIntoParamValError::OutOfRange { value, ty, .. } => write!(
+                        f,
+                        " expects a value that fits in `{}` but found {}.",
+                        ty, value
+                    ),
+                    TryIntoParamValError::SuffixMismatch { suffix, ty, .. } => write!(
+                        f,
+                        " expects a `{}` literal but found a `{}` literal.",
+                        ty, suffix
+                    ),
+                    other => write!(f, " has an invalid value: {}", other),
+                }
             }
-            FromParametersError::UnexpectedType => {
-                let message = "An unexpected type was provided to a parameter.";
-                ParseError::new(Span2::call_site(), message)
+            FromParametersError::DuplicateParam { repeat, .. } => {
+                write!(f, "The parameter '{}' was supplied more than once.", repeat)
+            }
+            FromParametersError::Nested(err) => err.fmt(f),
+        }
+    }
+}
+
+/// Writes the description of the parameter `param_key` that begins an error
+/// message.
+fn write_param(f: &mut Formatter<'_>, param_key: &ParamKey) -> FmtResult {
+    match param_key {
+        ParamKey::Named(..) => write!(f, "The parameter '{}'", param_key),
+        ParamKey::Unnamed(..) => write!(f, "The positional parameter at index {}", param_key),
+    }
+}
+
+impl Error for FromParametersError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            FromParametersError::InvalidValue { source, .. } => Some(source),
+            FromParametersError::Nested(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<FromParametersError> for ParseError {
+    fn from(src: FromParametersError) -> ParseError {
+        match src {
+            FromParametersError::DuplicateParam { first, repeat } => {
+                let message = format!("The parameter '{}' was supplied more than once.", repeat);
+                let mut err = ParseError::new(repeat.span(), message);
+                let message = format!("The parameter '{}' was first supplied here.", first);
+                err.combine(ParseError::new(first.span(), message));
+                err
             }
+            FromParametersError::Nested(err) => err,
+            other => ParseError::new(other.span(), other),
         }
     }
 }
@@ -38,73 +168,123 @@ impl<'a> From<FromParametersError<'a>> for ParseError {
 pub trait FromParameters: Sized {
     /// Try to create a type from a parameter struct (`params`) for a paramter
     /// of a specific name (`param_name`).
-    fn from_parameters<'a>(
+    fn from_parameters(
         params: &mut Parameters,
-        param_key: &'a ParamKey,
-    ) -> Result<Self, FromParametersError<'a>>;
+        param_key: &ParamKey,
+    ) -> Result<Self, FromParametersError>;
 }
 
 impl<T> FromParameters for T
 where
-    T: TryFrom<ParamVal>,
+    T: TryFrom<ParamVal, Error = TryIntoParamValError>,
 {
-    fn from_parameters<'a>(
+    fn from_parameters(
         params: &mut Parameters,
-        param_key: &'a ParamKey,
-    ) -> Result<Self, FromParametersError<'a>> {
-        if let Some(param_val) = params.remove(param_key) {
-            T::try_from(param_val).map_err(|_| FromParametersError::UnexpectedType)
+        param_key: &ParamKey,
+    ) -> Result<Self, FromParametersError> {
+        if let Some(param_val) = remove_single(params, param_key)? {
+            T::try_from(param_val).map_err(|err| FromParametersError::invalid_value(param_key, err))
         } else {
-            Err(FromParametersError::MissingParam { param_key })
+            Err(FromParametersError::MissingParam {
+                param_key: param_key.clone(),
+                span: params.span(),
+            })
         }
     }
 }
 
-impl FromParameters for Option<bool> {
-    fn from_parameters<'a>(
+impl<T> FromParameters for Option<T>
+where
+    T: TryFrom<ParamVal, Error = TryIntoParamValError>,
+{
+    fn from_parameters(
         params: &mut Parameters,
-        param_key: &'a ParamKey,
-    ) -> Result<Self, FromParametersError<'a>> {
-        Ok(params
-            .remove(param_key)
-            .map(|val| val.try_into().ok())
-            .flatten())
+        param_key: &ParamKey,
+    ) -> Result<Self, FromParametersError> {
+        remove_single(params, param_key)?
+            .map(T::try_from)
+            .transpose()
+            .map_err(|err| FromParametersError::invalid_value(param_key, err))
     }
 }
 
-impl FromParameters for Option<i64> {
-    fn from_parameters<'a>(
+/// A trait that is used to extract every occurrence of a parameter that may
+/// be supplied more than once from a `Parameters` struct.
+pub trait FromRepeatedParameters: Sized {
+    /// Try to create a type from every value supplied to the parameter of a
+    /// specific name (`param_key`) in the order they were written.
+    fn from_repeated_parameters(
         params: &mut Parameters,
-        param_key: &'a ParamKey,
-    ) -> Result<Self, FromParametersError<'a>> {
-        Ok(params
-            .remove(param_key)
-            .map(|val| val.try_into().ok())
-            .flatten())
-    }
+        param_key: &ParamKey,
+    ) -> Result<Self, FromParametersError>;
 }
 
-impl FromParameters for Option<f64> {
-    fn from_parameters<'a>(
+impl<T> FromRepeatedParameters for Vec<T>
+where
+    T: TryFrom<ParamVal, Error = TryIntoParamValError>,
+{
+    fn from_repeated_parameters(
         params: &mut Parameters,
-        param_key: &'a ParamKey,
-    ) -> Result<Self, FromParametersError<'a>> {
-        Ok(params
-            .remove(param_key)
-            .map(|val| val.try_into().ok())
-            .flatten())
+        param_key: &ParamKey,
+    ) -> Result<Self, FromParametersError> {
+        let mut values = vec![];
+        while let Some(param_val) = params.remove(param_key) {
+            let value = T::try_from(param_val)
+                .map_err(|err| FromParametersError::invalid_value(param_key, err))?;
+            values.push(value);
+        }
+
+        Ok(values)
     }
 }
 
-impl FromParameters for Option<String> {
-    fn from_parameters<'a>(
+/// A trait that is used to extract every remaining positional parameter from
+/// a `Parameters` struct.
+pub trait FromRestParameters: Sized {
+    /// Try to create a type from every positional parameter at or after the
+    /// position `start`.
+    fn from_rest_parameters(
         params: &mut Parameters,
-        param_key: &'a ParamKey,
-    ) -> Result<Self, FromParametersError<'a>> {
-        Ok(params
-            .remove(param_key)
-            .map(|val| val.try_into().ok())
-            .flatten())
+        start: usize,
+    ) -> Result<Self, FromParametersError>;
+}
+
+impl<T> FromRestParameters for Vec<T>
+where
+    T: TryFrom<ParamVal, Error = TryIntoParamValError>,
+{
+    fn from_rest_parameters(
+        params: &mut Parameters,
+        start: usize,
+    ) -> Result<Self, FromParametersError> {
+        let mut values = vec![];
+        for pos in start.. {
+            let param_key = pos.into();
+            match params.remove(&param_key) {
+                Some(param_val) => {
+                    let value = T::try_from(param_val)
+                        .map_err(|err| FromParametersError::invalid_value(&param_key, err))?;
+                    values.push(value);
+                }
+                None => break,
+            }
+        }
+
+        Ok(values)
+    }
+}
+
+/// Removes the value of a parameter which may only be supplied once.
+pub(crate) fn remove_single(
+    params: &mut Parameters,
+    param_key: &ParamKey,
+) -> Result<Option<ParamVal>, FromParametersError> {
+    match params.remove_entry(param_key) {
+        Some((first, param_val)) => match params.remove_entry(param_key) {
+            Some((repeat, _)) => Err(FromParametersError::DuplicateParam { first, repeat }),
+            None => Ok(Some(param_val)),
+        },
+        None => Ok(None),
     }
 }
 
@@ -118,7 +298,7 @@ mod tests {
     #[test]
     fn from_parameters_bool() {
         let mut params = Parameters::default();
-        params.insert("foo".into(), ParamVal::Bool(true));
+        params.insert("foo".into(), true.into());
         let param_key = "foo".into();
         let output = bool::from_parameters(&mut params, &param_key);
 
@@ -128,7 +308,7 @@ mod tests {
     #[test]
     fn from_parameters_str() {
         let mut params = Parameters::default();
-        params.insert("foo".into(), ParamVal::Int(1));
+        params.insert("foo".into(), 1.into());
         let param_key = "foo".into();
         let output = i64::from_parameters(&mut params, &param_key);
 
@@ -138,7 +318,7 @@ mod tests {
     #[test]
     fn from_parameters_int() {
         let mut params = Parameters::default();
-        params.insert("foo".into(), ParamVal::Str("bar".into()));
+        params.insert("foo".into(), "bar".into());
         let param_key = "foo".into();
         let output = String::from_parameters(&mut params, &param_key);
 
@@ -149,7 +329,7 @@ mod tests {
     #[test]
     fn from_parameters_bool_option() {
         let mut params = Parameters::default();
-        params.insert("foo".into(), ParamVal::Bool(true));
+        params.insert("foo".into(), true.into());
         let param_key = "foo".into();
         let output = Option::<bool>::from_parameters(&mut params, &param_key);
 
@@ -161,7 +341,7 @@ mod tests {
     #[test]
     fn from_parameters_str_option() {
         let mut params = Parameters::default();
-        params.insert("foo".into(), ParamVal::Int(1));
+        params.insert("foo".into(), 1.into());
         let param_key = "foo".into();
         let output = Option::<i64>::from_parameters(&mut params, &param_key);
 
@@ -182,4 +362,77 @@ mod tests {
         let no_output = Option::<String>::from_parameters(&mut params, &param_key);
         assert_eq!(no_output.unwrap(), None);
     }
+
+    #[test]
+    fn from_parameters_option_wrong_type() {
+        let mut params: Parameters = syn::parse_quote!(timeout = "ten", tags = ["a", "b"]);
+        let param_key = "timeout".into();
+        let output = Option::<i64>::from_parameters(&mut params, &param_key);
+        let err = output.unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "The parameter 'timeout' expects a value of type `i64` but found a string."
+        );
+
+        let param_key = "tags".into();
+        let output = Option::<Vec<String>>::from_parameters(&mut params, &param_key);
+        let right: Vec<String> = vec!["a".into(), "b".into()];
+        assert_eq!(output.unwrap(), Some(right));
+    }
+
+    #[test]
+    fn from_parameters_invalid_value() {
+        let mut params: Parameters = syn::parse_quote!(retries = 300, delay = 10u16);
+        let param_key = "retries".into();
+        let err = u8::from_parameters(&mut params, &param_key).unwrap_err();
+        assert!(matches!(
+            &err,
+            FromParametersError::InvalidValue { param_key, .. } if param_key == &"retries".into()
+        ));
+        assert_eq!(
+            err.to_string(),
+            "The parameter 'retries' expects a value that fits in `u8` but found 300."
+        );
+
+        let param_key = "delay".into();
+        let err = u8::from_parameters(&mut params, &param_key).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "The parameter 'delay' expects a `u8` literal but found a `u16` literal."
+        );
+    }
+
+    #[test]
+    fn from_parameters_duplicate() {
+        let mut params: Parameters = syn::parse_quote!(foo = "a", foo = "b");
+        let param_key = "foo".into();
+        let output = String::from_parameters(&mut params, &param_key);
+
+        assert!(matches!(
+            output,
+            Err(FromParametersError::DuplicateParam { .. })
+        ));
+    }
+
+    #[test]
+    fn from_repeated_parameters_vec() {
+        let mut params: Parameters = syn::parse_quote!(foo = "a", bar = 1, foo = "b");
+        let param_key = "foo".into();
+        let output = Vec::<String>::from_repeated_parameters(&mut params, &param_key);
+
+        let right: Vec<String> = vec!["a".into(), "b".into()];
+        assert_eq!(output.unwrap(), right);
+        let no_output = Vec::<String>::from_repeated_parameters(&mut params, &param_key);
+        assert_eq!(no_output.unwrap(), Vec::<String>::new());
+    }
+
+    #[test]
+    fn from_rest_parameters_vec() {
+        let mut params: Parameters = syn::parse_quote!("a", "b", "c", foo = 1);
+        let output = Vec::<String>::from_rest_parameters(&mut params, 1);
+
+        let right: Vec<String> = vec!["b".into(), "c".into()];
+        assert_eq!(output.unwrap(), right);
+        assert_eq!(params.len(), 2);
+    }
 }
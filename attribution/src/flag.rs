@@ -0,0 +1,83 @@
+use crate::conversion::remove_single;
+use crate::FromParameters;
+use crate::FromParametersError;
+use crate::ParamKey;
+use crate::ParamVal;
+use crate::Parameters;
+use crate::TryIntoParamValError;
+use proc_macro2::Span as Span2;
+
+/// A boolean parameter which is `false` when it is not supplied. A flag is set
+/// by naming it (e.g. `#[example(verbose)]`) and is explicitly unset with either
+/// `verbose = false` or `!verbose`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Flag {
+    value: bool,
+    span: Option<Span2>,
+}
+
+impl Flag {
+    /// Checks if the flag was set.
+    pub fn is_set(&self) -> bool {
+        self.value
+    }
+
+    /// The location where the flag was supplied, if it was supplied at all.
+    pub fn span(&self) -> Option<Span2> {
+        self.span
+    }
+}
+
+impl From<Flag> for bool {
+    fn from(flag: Flag) -> bool {
+        flag.value
+    }
+}
+
+impl FromParameters for Flag {
+    fn from_parameters(
+        params: &mut Parameters,
+        param_key: &ParamKey,
+    ) -> Result<Self, FromParametersError> {
+        match remove_single(params, param_key)? {
+            Some(ParamVal::Bool(lit)) => Ok(Flag {
+                value: lit.value,
+                span: Some(lit.span),
+            }),
+            Some(other) => Err(FromParametersError::invalid_value(
+                param_key,
+                TryIntoParamValError::unexpected_type::<Self>(&other),
+            )),
+            None => Ok(Flag::default()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use syn::parse_quote;
+
+    #[test]
+    fn flag_from_parameters() {
+        let mut params: Parameters = parse_quote!(verbose, !quiet, loud = false, level = 1);
+
+        let flag = Flag::from_parameters(&mut params, &"verbose".into()).unwrap();
+        assert_eq!(flag.is_set(), true);
+        assert!(flag.span().is_some());
+
+        let flag = Flag::from_parameters(&mut params, &"quiet".into()).unwrap();
+        assert_eq!(flag.is_set(), false);
+        assert!(flag.span().is_some());
+
+        let flag = Flag::from_parameters(&mut params, &"loud".into()).unwrap();
+        assert_eq!(flag.is_set(), false);
+
+        let flag = Flag::from_parameters(&mut params, &"missing".into()).unwrap();
+        assert_eq!(flag.is_set(), false);
+        assert!(flag.span().is_none());
+
+        assert!(Flag::from_parameters(&mut params, &"level".into()).is_err());
+    }
+}
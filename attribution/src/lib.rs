@@ -2,11 +2,23 @@
 #![allow(clippy::multiple_crate_versions)]
 
 mod conversion;
+mod flag;
 mod params;
+mod parse_str;
 
 pub use attribution_macros::AttrArgs;
 pub use conversion::FromParameters;
 pub use conversion::FromParametersError;
+pub use conversion::FromRepeatedParameters;
+pub use conversion::FromRestParameters;
+pub use flag::Flag;
 pub use params::DynamicParameters;
+pub use params::ParamArray;
+pub use params::ParamKey;
+pub use params::ParamList;
+pub use params::ParamMap;
+pub use params::ParamTuple;
 pub use params::ParamVal;
 pub use params::Parameters;
+pub use params::TryIntoParamValError;
+pub use parse_str::ParseStr;
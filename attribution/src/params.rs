@@ -2,83 +2,375 @@ mod keys;
 mod values;
 
 pub use self::keys::ParamKey;
+pub use self::values::ParamArray;
+pub use self::values::ParamList;
+pub use self::values::ParamMap;
+pub use self::values::ParamTuple;
 pub use self::values::ParamVal;
 pub use self::values::TryIntoParamValError;
 use crate::conversion::FromParameters;
 use crate::conversion::FromParametersError;
-use std::collections::HashMap;
+use proc_macro2::Span as Span2;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::ToTokens;
 use std::ops::Deref;
 use std::ops::DerefMut;
+use syn::ext::IdentExt;
+use syn::parse::discouraged::Speculative;
+use syn::parse::Error as ParseError;
 use syn::parse::Parse;
 use syn::parse::ParseStream;
 use syn::parse::Result as ParseResult;
+use syn::spanned::Spanned;
+use syn::token::Paren;
 use syn::Ident;
+use syn::LitBool;
 use syn::Token;
 
-/// Represents the mapping of parameter names to parameter values.
-#[derive(Debug, Default)]
-pub struct Parameters(HashMap<ParamKey, ParamVal>);
+/// Represents the mapping of parameter names to parameter values. Parameters
+/// are kept in the order they were written and looked up by a linear scan,
+/// which is cheaper than hashing for the handful of parameters an attribute
+/// typically has.
+#[derive(Clone, Debug)]
+pub struct Parameters {
+    entries: Vec<(ParamKey, ParamVal)>,
+    span: Span2,
+}
 
-impl Deref for Parameters {
-    type Target = HashMap<ParamKey, ParamVal>;
-    fn deref(&self) -> &Self::Target {
-        &self.0
+impl Parameters {
+    /// The span of the parameter list. For a parenthesized list this is the
+    /// span of the parentheses, otherwise it is the call site.
+    pub fn span(&self) -> Span2 {
+        self.span
+    }
+
+    /// The number of parameters in the list, including repeated keys.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the list contains no parameters.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns `true` if a value exists for `key`.
+    pub fn contains_key(&self, key: &ParamKey) -> bool {
+        self.position(key).is_some()
+    }
+
+    /// Gets the first value supplied for `key`.
+    pub fn get(&self, key: &ParamKey) -> Option<&ParamVal> {
+        self.position(key).map(|i| &self.entries[i].1)
+    }
+
+    /// Gets the first value supplied for `key` mutably.
+    pub fn get_mut(&mut self, key: &ParamKey) -> Option<&mut ParamVal> {
+        self.position(key).map(move |i| &mut self.entries[i].1)
+    }
+
+    /// Gets every value supplied for `key` in the order they were written.
+    pub fn get_all<'a>(&'a self, key: &'a ParamKey) -> impl Iterator<Item = &'a ParamVal> + 'a {
+        self.entries
+            .iter()
+            .filter(move |(entry_key, _)| entry_key == key)
+            .map(|(_, val)| val)
+    }
+
+    /// Sets the value of `key`, returning the value it replaced. If the key
+    /// has not been supplied the parameter is added to the end of the list.
+    pub fn insert(&mut self, key: ParamKey, val: ParamVal) -> Option<ParamVal> {
+        match self.get_mut(&key) {
+            Some(existing) => Some(core::mem::replace(existing, val)),
+            None => {
+                self.entries.push((key, val));
+                None
+            }
+        }
+    }
+
+    /// Adds a parameter to the end of the list. Unlike `insert`, any value
+    /// that already exists for `key` is kept.
+    pub fn push(&mut self, key: ParamKey, val: ParamVal) {
+        self.entries.push((key, val));
+    }
+
+    /// Removes the first value supplied for `key`.
+    pub fn remove(&mut self, key: &ParamKey) -> Option<ParamVal> {
+        self.remove_entry(key).map(|(_, val)| val)
+    }
+
+    /// Removes the first value supplied for `key` along with the key as it
+    /// was written.
+    pub fn remove_entry(&mut self, key: &ParamKey) -> Option<(ParamKey, ParamVal)> {
+        self.position(key).map(|i| self.entries.remove(i))
+    }
+
+    /// Iterates over the keys in the order they were written.
+    pub fn keys(&self) -> impl Iterator<Item = &ParamKey> {
+        self.entries.iter().map(|(key, _)| key)
+    }
+
+    /// Iterates over the parameters in the order they were written.
+    pub fn iter(&self) -> impl Iterator<Item = (&ParamKey, &ParamVal)> {
+        self.entries.iter().map(|(key, val)| (key, val))
+    }
+
+    /// Fails if any positional parameters remain in the list. This is used
+    /// once every expected positional parameter has been removed to report
+    /// the ones that were not expected.
+    pub fn deny_positional(&self) -> ParseResult<()> {
+        let mut unexpected = self.keys().filter_map(|key| match key {
+            ParamKey::Unnamed(pos, span) => {
+                let message = format!("Unexpected positional parameter at index {}.", pos);
+                Some(ParseError::new(*span, message))
+            }
+            ParamKey::Named(..) => None,
+        });
+
+        match unexpected.next() {
+            Some(mut err) => {
+                unexpected.for_each(|other| err.combine(other));
+                Err(err)
+            }
+            None => Ok(()),
+        }
+    }
+
+    /// Renames every parameter named `alias` to `key`. The renamed parameters
+    /// keep the span of the alias they were supplied with.
+    pub fn alias(&mut self, alias: &ParamKey, key: &ParamKey) {
+        for (entry_key, _) in self.entries.iter_mut() {
+            if entry_key == alias {
+                *entry_key = key.with_span(entry_key.span());
+            }
+        }
+    }
+
+    /// Parses a list of parameters wrapped in parentheses (e.g. the tokens
+    /// `(name = "value")` following the path of `#[example(name = "value")]`).
+    /// The parentheses are required and only the outermost pair is removed.
+    pub fn parse_parenthesized(input: ParseStream) -> ParseResult<Self> {
+        let content;
+        let paren_token = syn::parenthesized!(content in input);
+        let params = parse_list(&content)?;
+        Ok(Parameters {
+            span: paren_token.span,
+            ..params
+        })
+    }
+
+    /// Fails if any named parameters remain in the list whose name is not one
+    /// of `known`. Each unknown parameter is reported at its name along with
+    /// the most similar known name if one is close enough, otherwise every
+    /// known name is listed.
+    pub fn deny_unknown(&self, known: &[&str]) -> ParseResult<()> {
+        let mut unexpected = self.keys().filter_map(|key| match key {
+            ParamKey::Named(name, span) if !known.contains(&name.as_str()) => {
+                let suggestion = known
+                    .iter()
+                    .map(|candidate| (edit_distance(name, candidate), candidate))
+                    .filter(|(distance, _)| *distance <= (name.len() / 3).max(1))
+                    .min_by_key(|(distance, _)| *distance);
+
+                let message = match suggestion {
+                    Some((_, candidate)) => format!(
+                        "Unknown parameter '{}'. Did you mean '{}'?",
+                        name, candidate
+                    ),
+                    None if known.is_empty() => format!("Unknown parameter '{}'.", name),
+                    None => format!(
+                        "Unknown parameter '{}'. Expected one of: {}.",
+                        name,
+                        known
+                            .iter()
+                            .map(|candidate| format!("'{}'", candidate))
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    ),
+                };
+                Some(ParseError::new(*span, message))
+            }
+            _ => None,
+        });
+
+        match unexpected.next() {
+            Some(mut err) => {
+                unexpected.for_each(|other| err.combine(other));
+                Err(err)
+            }
+            None => Ok(()),
+        }
+    }
+
+    fn position(&self, key: &ParamKey) -> Option<usize> {
+        self.entries
+            .iter()
+            .position(|(entry_key, _)| entry_key == key)
     }
 }
 
-impl DerefMut for Parameters {
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.0
+/// The number of single character insertions, deletions, substitutions and
+/// transpositions of adjacent characters needed to turn `a` into `b`.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+
+    // distances[i][j] is the distance between the first i characters of `a`
+    // and the first j characters of `b`
+    let mut distances = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for (i, row) in distances.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, cell) in distances[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+            let mut distance = (distances[i - 1][j] + 1)
+                .min(distances[i][j - 1] + 1)
+                .min(distances[i - 1][j - 1] + cost);
+
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                distance = distance.min(distances[i - 2][j - 2] + 1);
+            }
+
+            distances[i][j] = distance;
+        }
+    }
+
+    distances[a.len()][b.len()]
+}
+
+impl Default for Parameters {
+    fn default() -> Self {
+        Parameters {
+            entries: Vec::default(),
+            span: Span2::call_site(),
+        }
+    }
+}
+
+impl PartialEq for Parameters {
+    fn eq(&self, other: &Self) -> bool {
+        self.entries == other.entries
+    }
+}
+
+impl IntoIterator for Parameters {
+    type Item = (ParamKey, ParamVal);
+    type IntoIter = std::vec::IntoIter<(ParamKey, ParamVal)>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries.into_iter()
     }
 }
 
 impl Parse for Parameters {
+    /// Parses the list of parameters without any surrounding parentheses (e.g.
+    /// the arguments of an attribute macro). Use
+    /// `Parameters::parse_parenthesized` for the tokens of an attribute which
+    /// still include the parentheses.
     fn parse(input: ParseStream) -> syn::parse::Result<Self> {
-        let mut params = Parameters::default();
+        parse_list(input)
+    }
+}
 
-        // If the content is wrapped in parentheses, unwrap it and parse the content.
-        if input.peek(syn::token::Paren) {
-            let content;
-            syn::parenthesized!(content in input);
-            let params = content.parse::<Parameters>();
-            if !content.is_empty() {
-                return Err(syn::parse::Error::new(
-                    input.span(),
-                    "Superfulous tokens in attribute body. Please remove.",
-                ));
-            } else {
-                return params;
+impl ToTokens for Parameters {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        for (i, (key, val)) in self.iter().enumerate() {
+            if i > 0 {
+                <Token![,]>::default().to_tokens(tokens);
+            }
+
+            match (key, val) {
+                (ParamKey::Named(..), ParamVal::Nested(_)) => {
+                    key.to_tokens(tokens);
+                    val.to_tokens(tokens);
+                }
+                (ParamKey::Named(..), _) => {
+                    key.to_tokens(tokens);
+                    <Token![=]>::default().to_tokens(tokens);
+                    val.to_tokens(tokens);
+                }
+                (ParamKey::Unnamed(..), _) => val.to_tokens(tokens),
             }
         }
+    }
+}
 
-        let mut pos = 0;
-        while !input.is_empty() {
-            let (key, val) = parse_kv(input)?;
-            let key = key
-                .map(|i| ParamKey::Named(i.to_string()))
-                .unwrap_or_else(|| ParamKey::Unnamed(pos));
-            params.insert(key, val);
+/// Parses a comma separated list of parameters until the input is exhausted.
+/// Positional parameters are numbered independently of named parameters and
+/// must all appear before the first named parameter.
+fn parse_list(input: ParseStream) -> ParseResult<Parameters> {
+    let mut params = Parameters::default();
 
-            if input.peek(Token!(,)) {
-                input.parse::<Token!(,)>()?;
+    let mut pos = 0;
+    let mut first_named = None;
+    while !input.is_empty() {
+        match parse_kv(input)? {
+            (Some(key), val) => {
+                first_named.get_or_insert_with(|| key.clone());
+                params.push(key, val);
             }
+            (None, val) => {
+                if let Some(first_named) = &first_named {
+                    let mut err = ParseError::new(
+                        val.span(),
+                        "Positional parameters must come before named parameters.",
+                    );
+                    let message =
+                        format!("The named parameter '{}' is supplied here.", first_named);
+                    err.combine(ParseError::new(first_named.span(), message));
+                    return Err(err);
+                }
 
-            pos += 1;
+                params.push(ParamKey::Unnamed(pos, val.span()), val);
+                pos += 1;
+            }
         }
 
-        Ok(params)
+        if input.peek(Token!(,)) {
+            input.parse::<Token!(,)>()?;
+        }
     }
+
+    Ok(params)
 }
 
-fn parse_kv(input: ParseStream) -> ParseResult<(Option<Ident>, ParamVal)> {
-    match input.parse::<Ident>() {
+fn parse_kv(input: ParseStream) -> ParseResult<(Option<ParamKey>, ParamVal)> {
+    if let Some(key) = parse_negated_flag(input) {
+        let val = ParamVal::Bool(LitBool::new(false, key.span()));
+        return Ok((Some(key), val));
+    }
+
+    match parse_key(input) {
+        Ok(i) if input.peek(Paren) => {
+            let content;
+            let paren_token = syn::parenthesized!(content in input);
+            let params = Parameters {
+                span: paren_token.span,
+                ..parse_list(&content)?
+            };
+            Ok((
+                Some(i),
+                ParamVal::Nested(ParamList {
+                    paren_token,
+                    params,
+                }),
+            ))
+        }
         Ok(i) => match input.parse::<Token!(=)>() {
             Ok(_) => {
                 let val = input.parse()?;
                 Ok((Some(i), val))
             }
 
-            Err(_) => Ok((Some(i), ParamVal::Bool(true))),
+            Err(_) => {
+                let val = ParamVal::Bool(LitBool::new(true, i.span()));
+                Ok((Some(i), val))
+            }
         },
         Err(_) => {
             let val = input.parse()?;
@@ -87,6 +379,60 @@ fn parse_kv(input: ParseStream) -> ParseResult<(Option<Ident>, ParamVal)> {
     }
 }
 
+/// Parses a negated flag (e.g. `!verbose`), returning the name of the flag.
+/// Nothing is consumed if the input does not begin with a negated flag.
+fn parse_negated_flag(input: ParseStream) -> Option<ParamKey> {
+    if !input.peek(Token![!]) {
+        return None;
+    }
+
+    let fork = input.fork();
+    let key = fork
+        .parse::<Token![!]>()
+        .and_then(|_| parse_key(&fork))
+        .ok()?;
+
+    if fork.is_empty() || fork.peek(Token![,]) {
+        input.advance_to(&fork);
+        Some(key)
+    } else {
+        None
+    }
+}
+
+/// Parses the name of a named parameter. Keywords (e.g. `type` or `crate`)
+/// and raw identifiers are accepted, with the exception of `true` and `false`
+/// which are always parsed as values. Identifiers joined by hyphens (e.g.
+/// `max-age`) form a single name. A name must be followed by `=`, `,`, a
+/// nested parameter list or the end of the input, otherwise the tokens are the
+/// start of a value (e.g. `my::func` or `a - b`).
+fn parse_key(input: ParseStream) -> ParseResult<ParamKey> {
+    if input.peek(LitBool) {
+        return Err(input.error("Expected a parameter name."));
+    }
+
+    let fork = input.fork();
+    let first = fork.call(Ident::parse_any)?;
+    let mut name = first.unraw().to_string();
+    while fork.peek(Token![-]) && fork.peek2(Ident::peek_any) {
+        fork.parse::<Token![-]>()?;
+        name.push('-');
+        name.push_str(&fork.call(Ident::parse_any)?.unraw().to_string());
+    }
+
+    if is_key_end(&fork) {
+        input.advance_to(&fork);
+        Ok(ParamKey::Named(name, first.span()))
+    } else {
+        Err(fork.error("Expected a parameter name."))
+    }
+}
+
+/// Checks if the input is positioned at the end of a parameter name.
+fn is_key_end(input: ParseStream) -> bool {
+    input.is_empty() || input.peek(Token![=]) || input.peek(Token![,]) || input.peek(Paren)
+}
+
 /// An object that is used to aggregate any remaining parameters into
 /// the struct tagged with `attribution::attr_args`.
 #[derive(Default)]
@@ -106,16 +452,9 @@ impl DerefMut for DynamicParameters {
 }
 
 impl FromParameters for DynamicParameters {
-    fn from_parameters<'a>(
-        params: &mut Parameters,
-        _: &'a ParamKey,
-    ) -> Result<Self, FromParametersError<'a>> {
+    fn from_parameters(params: &mut Parameters, _: &ParamKey) -> Result<Self, FromParametersError> {
         let mut ret = DynamicParameters(Parameters::default());
-        let keys: Vec<_> = params.0.keys().cloned().collect();
-        for key in keys {
-            let val = params.0.remove(&key).unwrap();
-            (ret.0).0.insert(key, val);
-        }
+        ret.0.entries.append(&mut params.entries);
 
         Ok(ret)
     }
@@ -124,7 +463,10 @@ impl FromParameters for DynamicParameters {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use core::convert::TryInto;
     use pretty_assertions::assert_eq;
+    use quote::quote;
+    use syn::parse::Parser;
     use syn::parse2;
     use syn::parse_quote;
     use syn::Attribute;
@@ -141,13 +483,254 @@ mod tests {
             let simple_flag_val = attr_args.get(&"simple_flag".into());
             let other_val = attr_args.get(&"other".into());
 
-            assert_eq!(string_val, Some(&ParamVal::Str("fooValue".to_string())));
-            assert_eq!(integer_val, Some(&ParamVal::Int(1)));
-            assert_eq!(flag_val, Some(&ParamVal::Bool(true)));
-            assert_eq!(simple_flag_val, Some(&ParamVal::Bool(true)));
+            assert_eq!(string_val, Some(&ParamVal::from("fooValue")));
+            assert_eq!(integer_val, Some(&ParamVal::from(1)));
+            assert_eq!(flag_val, Some(&ParamVal::from(true)));
+            assert_eq!(simple_flag_val, Some(&ParamVal::from(true)));
             assert_eq!(other_val, None);
         } else {
             panic!("Didn't unwrap appropriately");
         }
     }
+
+    #[test]
+    fn parse_nested() {
+        let attr_args: Parameters = parse_quote!(get(path = "/x"), auth(required));
+        let get_val = attr_args.get(&"get".into()).cloned();
+        let auth_val = attr_args.get(&"auth".into()).cloned();
+
+        let get_params: Parameters = parse_quote!(path = "/x");
+        let auth_params: Parameters = parse_quote!(required);
+        assert_eq!(
+            get_val.and_then(|val| val.try_into().ok()),
+            Some(get_params)
+        );
+        assert_eq!(
+            auth_val.and_then(|val| val.try_into().ok()),
+            Some(auth_params)
+        );
+    }
+
+    #[test]
+    fn parse_repeated() {
+        let attr_args: Parameters = parse_quote!(name = "a", other = 1, name = "b");
+        let name_vals: Vec<_> = attr_args.get_all(&"name".into()).cloned().collect();
+
+        assert_eq!(attr_args.get(&"name".into()), Some(&ParamVal::from("a")));
+        assert_eq!(name_vals, vec![ParamVal::from("a"), ParamVal::from("b")]);
+    }
+
+    #[test]
+    fn parse_preserves_order() {
+        let attr_args: Parameters = parse_quote!(c = 1, a = 2, b = 3);
+        let keys: Vec<_> = attr_args.keys().map(ToString::to_string).collect();
+
+        assert_eq!(keys, vec!["c", "a", "b"]);
+    }
+
+    #[test]
+    fn parse_positional() {
+        let attr_args: Parameters = parse_quote!("foo", 1, mode = 1);
+        let keys: Vec<_> = attr_args.keys().map(ToString::to_string).collect();
+
+        assert_eq!(keys, vec!["0", "1", "mode"]);
+        assert_eq!(attr_args.get(&0.into()), Some(&ParamVal::from("foo")));
+    }
+
+    #[test]
+    fn parse_positional_after_named() {
+        let attr_args: ParseResult<Parameters> = syn::parse_str(r#"mode = 1, "foo""#);
+        assert!(attr_args.is_err());
+    }
+
+    #[test]
+    fn deny_positional() {
+        let mut attr_args: Parameters = parse_quote!("foo", "bar", mode = 1);
+        attr_args.remove(&0.into());
+
+        assert!(attr_args.deny_positional().is_err());
+        attr_args.remove(&1.into());
+        assert!(attr_args.deny_positional().is_ok());
+    }
+
+    #[test]
+    fn parse_keyword_keys() {
+        let attr_args: Parameters =
+            parse_quote!(true, type = "x", crate = "y", ref = 1, r#async, in = 2, move);
+        let keys: Vec<_> = attr_args.keys().map(ToString::to_string).collect();
+
+        assert_eq!(
+            keys,
+            vec!["0", "type", "crate", "ref", "async", "in", "move"]
+        );
+        assert_eq!(attr_args.get(&0.into()), Some(&ParamVal::from(true)));
+    }
+
+    #[test]
+    fn parse_keyword_exprs() {
+        let attr_args: Parameters = parse_quote!(move || x, retries = 3);
+        let expected: ParamVal = parse_quote!(move || x);
+
+        assert_eq!(attr_args.get(&0.into()), Some(&expected));
+        assert!(!attr_args.contains_key(&"move".into()));
+
+        let attr_args: Result<Parameters, _> = syn::parse_str("a b c");
+        assert!(attr_args.is_err());
+    }
+
+    #[test]
+    fn parse_paths() {
+        let attr_args: Parameters = parse_quote!(my::func, with = my::module::func, kind = Fast);
+
+        assert_eq!(
+            attr_args.get(&0.into()),
+            Some(&ParamVal::Path(parse_quote!(my::func)))
+        );
+        assert_eq!(
+            attr_args.get(&"with".into()),
+            Some(&ParamVal::Path(parse_quote!(my::module::func)))
+        );
+        assert_eq!(
+            attr_args.get(&"kind".into()),
+            Some(&ParamVal::Path(parse_quote!(Fast)))
+        );
+    }
+
+    #[test]
+    fn parse_exprs_and_types() {
+        let attr_args: Parameters = parse_quote!(
+            delay = Duration::from_secs(5),
+            when = |e| e.is_transient(),
+            inner = HashMap<K, V>
+        );
+
+        assert_eq!(
+            attr_args.get(&"delay".into()),
+            Some(&ParamVal::Expr(Box::new(parse_quote!(
+                Duration::from_secs(5)
+            ))))
+        );
+        assert_eq!(
+            attr_args.get(&"when".into()),
+            Some(&ParamVal::Expr(Box::new(
+                parse_quote!(|e| e.is_transient())
+            )))
+        );
+        assert_eq!(
+            attr_args.get(&"inner".into()),
+            Some(&ParamVal::Type(Box::new(parse_quote!(HashMap<K, V>))))
+        );
+    }
+
+    #[test]
+    fn parse_tuple_params() {
+        let attr_args: Parameters = parse_quote!((1, "a"), pairs = [("a", 1)]);
+        assert_eq!(attr_args.len(), 2);
+        assert!(matches!(attr_args.get(&0.into()), Some(ParamVal::Tuple(_))));
+
+        let attr_args = Parameters::parse_parenthesized
+            .parse2(quote!((x = 1)))
+            .unwrap();
+        assert_eq!(attr_args.get(&"x".into()), Some(&ParamVal::from(1)));
+    }
+
+    #[test]
+    fn parse_single_tuple_param() {
+        let expected: ParamVal = parse_quote!(("a", 1));
+
+        // The arguments of the attribute macro `#[attr(("a", 1))]`
+        let attr_args: Parameters = parse_quote!(("a", 1));
+        assert_eq!(attr_args.len(), 1);
+        assert_eq!(attr_args.get(&0.into()), Some(&expected));
+
+        // The tokens of the attribute `#[attr(("a", 1))]` following its path
+        let attr_args = Parameters::parse_parenthesized
+            .parse2(quote!((("a", 1))))
+            .unwrap();
+        assert_eq!(attr_args.len(), 1);
+        assert_eq!(attr_args.get(&0.into()), Some(&expected));
+
+        let attr_args = Parameters::parse_parenthesized.parse2(quote!(("a", 1), "b"));
+        assert!(attr_args.is_err());
+    }
+
+    #[test]
+    fn deny_unknown() {
+        let attr_args: Parameters = parse_quote!(start = "a");
+        assert!(attr_args.deny_unknown(&["start", "end"]).is_ok());
+
+        let attr_args: Parameters = parse_quote!(strat = "a", colour = 1);
+        let err = attr_args.deny_unknown(&["start", "end"]).unwrap_err();
+        let messages: Vec<String> = err.into_iter().map(|err| err.to_string()).collect();
+        assert_eq!(
+            messages,
+            vec![
+                "Unknown parameter 'strat'. Did you mean 'start'?",
+                "Unknown parameter 'colour'. Expected one of: 'start', 'end'."
+            ]
+        );
+    }
+
+    #[test]
+    fn edit_distances() {
+        assert_eq!(edit_distance("start", "start"), 0);
+        assert_eq!(edit_distance("strat", "start"), 1);
+        assert_eq!(edit_distance("sart", "start"), 1);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+    }
+
+    #[test]
+    fn parse_negated_flags() {
+        let attr_args: Parameters = parse_quote!(!check(), !quiet, verbose);
+
+        assert_eq!(attr_args.get(&"quiet".into()), Some(&ParamVal::from(false)));
+        assert_eq!(
+            attr_args.get(&"verbose".into()),
+            Some(&ParamVal::from(true))
+        );
+        assert!(matches!(attr_args.get(&0.into()), Some(ParamVal::Expr(_))));
+    }
+
+    #[test]
+    fn parse_hyphenated_keys() {
+        let attr_args: Parameters = parse_quote!(max - age = 10, no - cache, !no - store);
+
+        assert_eq!(attr_args.get(&"max-age".into()), Some(&ParamVal::from(10)));
+        assert_eq!(
+            attr_args.get(&"no-cache".into()),
+            Some(&ParamVal::from(true))
+        );
+        assert_eq!(
+            attr_args.get(&"no-store".into()),
+            Some(&ParamVal::from(false))
+        );
+
+        let reparsed: Parameters = parse_quote!(#attr_args);
+        assert_eq!(attr_args, reparsed);
+    }
+
+    #[test]
+    fn parse_subtraction_value() {
+        let attr_args: Parameters = parse_quote!(a - b + c);
+        let expected: ParamVal = parse_quote!(a - b + c);
+
+        assert_eq!(attr_args.len(), 1);
+        assert_eq!(attr_args.get(&0.into()), Some(&expected));
+        assert!(!attr_args.contains_key(&"a-b".into()));
+
+        let attr_args: Parameters = parse_quote!(x = a - b, y = 1);
+        let expected: ParamVal = parse_quote!(a - b);
+
+        assert_eq!(attr_args.get(&"x".into()), Some(&expected));
+        assert!(!attr_args.contains_key(&"a-b".into()));
+    }
+
+    #[test]
+    fn alias() {
+        let mut attr_args: Parameters = parse_quote!(colour = "red", size = 1);
+        attr_args.alias(&"colour".into(), &"color".into());
+
+        assert_eq!(attr_args.get(&"color".into()), Some(&ParamVal::from("red")));
+        assert!(!attr_args.contains_key(&"colour".into()));
+    }
 }
@@ -1,37 +1,144 @@
 use core::fmt::Display;
 use core::fmt::Formatter;
 use core::fmt::Result as FmtResult;
+use core::hash::Hash;
+use core::hash::Hasher;
+use proc_macro2::Literal;
+use proc_macro2::Punct;
+use proc_macro2::Spacing;
+use proc_macro2::Span as Span2;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::ToTokens;
+use quote::TokenStreamExt;
+use syn::ext::IdentExt;
+use syn::Ident;
 
-/// Represents an identifier for a `ParamVal`
-#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+/// Represents an identifier for a `ParamVal`. Each key carries the span of
+/// the source that produced it, but the span takes no part in comparing or
+/// hashing keys.
+#[derive(Clone, Debug)]
 pub enum ParamKey {
-    Named(String),
-    Unnamed(usize),
+    Named(String, Span2),
+    Unnamed(usize, Span2),
+}
+
+impl ParamKey {
+    /// The location of the source that produced the key.
+    pub fn span(&self) -> Span2 {
+        match self {
+            ParamKey::Named(_, span) => *span,
+            ParamKey::Unnamed(_, span) => *span,
+        }
+    }
+
+    /// Creates a copy of the key that is located at `span`.
+    pub fn with_span(&self, span: Span2) -> Self {
+        match self {
+            ParamKey::Named(name, _) => ParamKey::Named(name.clone(), span),
+            ParamKey::Unnamed(pos, _) => ParamKey::Unnamed(*pos, span),
+        }
+    }
+}
+
+impl PartialEq for ParamKey {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (ParamKey::Named(left, _), ParamKey::Named(right, _)) => left == right,
+            (ParamKey::Unnamed(left, _), ParamKey::Unnamed(right, _)) => left == right,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for ParamKey {}
+
+impl Hash for ParamKey {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            ParamKey::Named(name, _) => name.hash(state),
+            ParamKey::Unnamed(pos, _) => pos.hash(state),
+        }
+    }
 }
 
 impl Display for ParamKey {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         match self {
-            ParamKey::Named(name) => name.fmt(f),
-            ParamKey::Unnamed(pos) => pos.fmt(f),
+            ParamKey::Named(name, _) => name.fmt(f),
+            ParamKey::Unnamed(pos, _) => pos.fmt(f),
         }
     }
 }
 
+impl ToTokens for ParamKey {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        match self {
+            ParamKey::Named(name, span) => {
+                // Hyphenated names (e.g. `max-age`) are emitted as identifiers
+                // joined by hyphens
+                for (i, segment) in name.split('-').enumerate() {
+                    if i > 0 {
+                        let mut hyphen = Punct::new('-', Spacing::Alone);
+                        hyphen.set_span(*span);
+                        tokens.append(hyphen);
+                    }
+
+                    tokens.append(Ident::new(segment, *span));
+                }
+            }
+            ParamKey::Unnamed(pos, span) => {
+                let mut lit = Literal::usize_unsuffixed(*pos);
+                lit.set_span(*span);
+                tokens.append(lit);
+            }
+        }
+    }
+}
+
+impl From<Ident> for ParamKey {
+    /// Creates a named key from an identifier. Raw identifiers (e.g. `r#type`)
+    /// produce the key without the `r#` prefix.
+    fn from(src: Ident) -> Self {
+        ParamKey::Named(src.unraw().to_string(), src.span())
+    }
+}
+
 impl From<String> for ParamKey {
     fn from(src: String) -> Self {
-        ParamKey::Named(src)
+        ParamKey::Named(src, Span2::call_site())
     }
 }
 
 impl From<&str> for ParamKey {
     fn from(src: &str) -> Self {
-        ParamKey::Named(src.into())
+        ParamKey::Named(src.into(), Span2::call_site())
     }
 }
 
 impl From<usize> for ParamKey {
     fn from(src: usize) -> Self {
-        ParamKey::Unnamed(src)
+        ParamKey::Unnamed(src, Span2::call_site())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use syn::parse_quote;
+
+    #[test]
+    fn span_is_ignored_by_eq() {
+        let ident: Ident = parse_quote!(foo);
+        let spanned_key = ParamKey::from(ident);
+        let lookup_key = ParamKey::from("foo");
+
+        assert_eq!(spanned_key, lookup_key);
+    }
+
+    #[test]
+    fn raw_ident_is_unprefixed() {
+        let ident: Ident = parse_quote!(r#type);
+        assert_eq!(ParamKey::from(ident), ParamKey::from("type"));
     }
 }
@@ -2,16 +2,172 @@ mod convert;
 mod parsing;
 
 pub use self::convert::TryIntoParamValError;
+use crate::Parameters;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::ToTokens;
+use syn::token::Brace;
+use syn::token::Bracket;
+use syn::token::Paren;
+use syn::Expr;
+use syn::LitBool;
+use syn::LitByte;
+use syn::LitByteStr;
+use syn::LitChar;
+use syn::LitFloat;
+use syn::LitInt;
+use syn::LitStr;
+use syn::Path;
+use syn::Token;
+use syn::Type;
 
 /// Represents a value for a parameter name within `Parameters` struct.
 /// The parameter value is the value that appears to the right of the equal
 /// sign (e.g. `"value"` is the `ParamVal` in the following example
-/// `#[example(name = "value")]`)
-#[derive(Debug, PartialEq)]
+/// `#[example(name = "value")]`). Each value retains the tokens it was parsed
+/// from so that its span can be recovered through `syn::spanned::Spanned`.
+#[derive(Clone, Debug, PartialEq)]
 pub enum ParamVal {
-    Array(Vec<ParamVal>),
-    Bool(bool),
-    Int(i64),
-    Float(f64),
-    Str(String),
+    Array(ParamArray),
+    Map(ParamMap),
+    Tuple(ParamTuple),
+    Bool(LitBool),
+    Int(LitInt),
+    Float(LitFloat),
+    Str(LitStr),
+    Char(LitChar),
+    Byte(LitByte),
+    ByteStr(LitByteStr),
+    Nested(ParamList),
+    Path(Path),
+    Type(Box<Type>),
+    Expr(Box<Expr>),
+}
+
+/// An array of parameter values (e.g. `[1, 2, 3]`)
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParamArray {
+    pub bracket_token: Bracket,
+    pub elems: Vec<ParamVal>,
+}
+
+/// A tuple of parameter values (e.g. `("a", 1)`)
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParamTuple {
+    pub paren_token: Paren,
+    pub elems: Vec<ParamVal>,
+}
+
+/// A map of parameter values (e.g. `{ "Accept": "json" }`)
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParamMap {
+    pub brace_token: Brace,
+    pub entries: Vec<(ParamVal, ParamVal)>,
+}
+
+/// A parenthesized list of parameters that follows a key (e.g. the
+/// `(path = "/x")` in `get(path = "/x")`)
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParamList {
+    pub paren_token: Paren,
+    pub params: Parameters,
+}
+
+impl ParamVal {
+    /// A description of the kind of value (e.g. `an integer`) that is used in
+    /// error messages.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            ParamVal::Array(_) => "an array",
+            ParamVal::Map(_) => "a map",
+            ParamVal::Tuple(_) => "a tuple",
+            ParamVal::Bool(_) => "a boolean",
+            ParamVal::Int(_) => "an integer",
+            ParamVal::Float(_) => "a float",
+            ParamVal::Str(_) => "a string",
+            ParamVal::Char(_) => "a character",
+            ParamVal::Byte(_) => "a byte",
+            ParamVal::ByteStr(_) => "a byte string",
+            ParamVal::Nested(_) => "a nested parameter list",
+            ParamVal::Path(_) => "a path",
+            ParamVal::Type(_) => "a type",
+            ParamVal::Expr(_) => "an expression",
+        }
+    }
+}
+
+impl ToTokens for ParamVal {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        match self {
+            ParamVal::Array(array) => array.to_tokens(tokens),
+            ParamVal::Map(map) => map.to_tokens(tokens),
+            ParamVal::Tuple(tuple) => tuple.to_tokens(tokens),
+            ParamVal::Bool(lit) => lit.to_tokens(tokens),
+            ParamVal::Int(lit) => lit.to_tokens(tokens),
+            ParamVal::Float(lit) => lit.to_tokens(tokens),
+            ParamVal::Str(lit) => lit.to_tokens(tokens),
+            ParamVal::Char(lit) => lit.to_tokens(tokens),
+            ParamVal::Byte(lit) => lit.to_tokens(tokens),
+            ParamVal::ByteStr(lit) => lit.to_tokens(tokens),
+            ParamVal::Nested(list) => list.to_tokens(tokens),
+            ParamVal::Path(path) => path.to_tokens(tokens),
+            ParamVal::Type(ty) => ty.to_tokens(tokens),
+            ParamVal::Expr(expr) => expr.to_tokens(tokens),
+        }
+    }
+}
+
+impl ToTokens for ParamArray {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        self.bracket_token.surround(tokens, |tokens| {
+            for (i, elem) in self.elems.iter().enumerate() {
+                if i > 0 {
+                    <Token![,]>::default().to_tokens(tokens);
+                }
+
+                elem.to_tokens(tokens);
+            }
+        })
+    }
+}
+
+impl ToTokens for ParamTuple {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        self.paren_token.surround(tokens, |tokens| {
+            for (i, elem) in self.elems.iter().enumerate() {
+                if i > 0 {
+                    <Token![,]>::default().to_tokens(tokens);
+                }
+
+                elem.to_tokens(tokens);
+            }
+
+            // A single element tuple requires a trailing comma
+            if self.elems.len() == 1 {
+                <Token![,]>::default().to_tokens(tokens);
+            }
+        })
+    }
+}
+
+impl ToTokens for ParamMap {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        self.brace_token.surround(tokens, |tokens| {
+            for (i, (key, val)) in self.entries.iter().enumerate() {
+                if i > 0 {
+                    <Token![,]>::default().to_tokens(tokens);
+                }
+
+                key.to_tokens(tokens);
+                <Token![:]>::default().to_tokens(tokens);
+                val.to_tokens(tokens);
+            }
+        })
+    }
+}
+
+impl ToTokens for ParamList {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        self.paren_token
+            .surround(tokens, |tokens| self.params.to_tokens(tokens))
+    }
 }
@@ -1,20 +1,96 @@
+use crate::params::ParamArray;
 use crate::ParamVal;
 use metafor::metafor;
+use proc_macro2::Literal;
+use proc_macro2::Span as Span2;
+use syn::parse_quote;
+use syn::Ident;
+use syn::LitBool;
+use syn::LitChar;
+use syn::LitStr;
+
+impl From<bool> for ParamVal {
+    fn from(src: bool) -> Self {
+        ParamVal::Bool(LitBool::new(src, Span2::call_site()))
+    }
+}
 
 #[metafor(variant = [
-    { name: Bool, ty: bool },
-    { name: Int, ty: i64 },
-    { name: Float, ty: f64 },
-    { name: Str, ty: String }
+    { name: Int, ty: u8, ctor: u8_unsuffixed },
+    { name: Int, ty: u16, ctor: u16_unsuffixed },
+    { name: Int, ty: u32, ctor: u32_unsuffixed },
+    { name: Int, ty: u64, ctor: u64_unsuffixed },
+    { name: Int, ty: u128, ctor: u128_unsuffixed },
+    { name: Int, ty: usize, ctor: usize_unsuffixed },
+    { name: Int, ty: i8, ctor: i8_unsuffixed },
+    { name: Int, ty: i16, ctor: i16_unsuffixed },
+    { name: Int, ty: i32, ctor: i32_unsuffixed },
+    { name: Int, ty: i64, ctor: i64_unsuffixed },
+    { name: Int, ty: i128, ctor: i128_unsuffixed },
+    { name: Int, ty: isize, ctor: isize_unsuffixed }
 ])]
 impl From<__variant__ty__> for ParamVal {
     fn from(src: __variant__ty__) -> Self {
-        ParamVal::__variant__name__(src)
+        ParamVal::__variant__name__(Literal::__variant__ctor__(src).into())
+    }
+}
+
+impl From<f32> for ParamVal {
+    fn from(src: f32) -> Self {
+        float_param_val(src, "f32", Literal::f32_unsuffixed)
+    }
+}
+
+impl From<f64> for ParamVal {
+    fn from(src: f64) -> Self {
+        float_param_val(src, "f64", Literal::f64_unsuffixed)
+    }
+}
+
+/// Creates a float value. Non-finite values have no literal form, so they are
+/// represented by the path of the matching constant instead (e.g. `f64::NAN`).
+fn float_param_val<T: Copy + Into<f64>>(src: T, ty: &str, ctor: fn(T) -> Literal) -> ParamVal {
+    let value = src.into();
+    if value.is_finite() {
+        return ParamVal::Float(ctor(src).into());
+    }
+
+    let constant = if value.is_nan() {
+        "NAN"
+    } else if value > 0.0 {
+        "INFINITY"
+    } else {
+        "NEG_INFINITY"
+    };
+
+    let ty = Ident::new(ty, Span2::call_site());
+    let constant = Ident::new(constant, Span2::call_site());
+    ParamVal::Path(parse_quote!(#ty::#constant))
+}
+
+impl From<char> for ParamVal {
+    fn from(src: char) -> Self {
+        ParamVal::Char(LitChar::new(src, Span2::call_site()))
+    }
+}
+
+impl From<String> for ParamVal {
+    fn from(src: String) -> Self {
+        ParamVal::Str(LitStr::new(&src, Span2::call_site()))
     }
 }
 
 impl From<&str> for ParamVal {
     fn from(src: &str) -> Self {
-        ParamVal::Str(src.into())
+        ParamVal::Str(LitStr::new(src, Span2::call_site()))
+    }
+}
+
+impl From<Vec<ParamVal>> for ParamVal {
+    fn from(src: Vec<ParamVal>) -> Self {
+        ParamVal::Array(ParamArray {
+            bracket_token: Default::default(),
+            elems: src,
+        })
     }
 }
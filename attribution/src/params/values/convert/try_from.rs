@@ -1,30 +1,336 @@
+use crate::ParamArray;
+use crate::ParamTuple;
 use crate::ParamVal;
+use crate::Parameters;
+use core::any::type_name;
 use core::convert::TryFrom;
 use core::convert::TryInto;
 use metafor::metafor;
+use proc_macro2::Literal;
+use proc_macro2::Span as Span2;
+use quote::ToTokens;
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::fmt::Result as FmtResult;
+use std::hash::Hash;
+use std::str::FromStr;
+use syn::ext::IdentExt;
+use syn::parse::Error as ParseError;
+use syn::spanned::Spanned;
+use syn::Expr;
+use syn::Ident;
+use syn::Path;
+use syn::Type;
+use syn::TypePath;
 
 /// An error that is received as a result of not being able to convert a `ParamVal`
 /// into a given type. This is due to the `ParmaVal` not being of the correct variant
 /// for the type that the `ParamVal` is being converted into.
-#[derive(Debug, PartialEq)]
+#[derive(Debug)]
 pub enum TryIntoParamValError {
-    UnexpectedType,
+    /// Indicates the `ParamVal` was not a kind of value that can be converted
+    /// into the `expected` type.
+    UnexpectedType {
+        span: Span2,
+        expected: String,
+        found: &'static str,
+    },
+
+    /// Indicates the `ParamVal` was a number that cannot be represented by the
+    /// numeric type it was converted into.
+    OutOfRange {
+        span: Span2,
+        value: String,
+        ty: &'static str,
+    },
+
+    /// Indicates the `ParamVal` was a numeric literal whose suffix (e.g. the
+    /// `u16` in `10u16`) does not match the type it was converted into.
+    SuffixMismatch {
+        span: Span2,
+        suffix: String,
+        ty: &'static str,
+    },
+
+    /// Indicates the `ParamVal` was a nested parameter list whose contents could
+    /// not be converted.
+    Nested(ParseError),
+}
+
+impl TryIntoParamValError {
+    /// Creates an `UnexpectedType` error for a value which could not be
+    /// converted into `T`, located at the provided value.
+    pub fn unexpected_type<T>(param_val: &ParamVal) -> Self {
+        TryIntoParamValError::UnexpectedType {
+            span: param_val.span(),
+            expected: short_type_name::<T>(),
+            found: param_val.kind(),
+        }
+    }
+
+    /// The location of the value that could not be converted.
+    pub fn span(&self) -> Span2 {
+        match self {
+            TryIntoParamValError::UnexpectedType { span, .. } => *span,
+            TryIntoParamValError::OutOfRange { span, .. } => *span,
+            TryIntoParamValError::SuffixMismatch { span, .. } => *span,
+            TryIntoParamValError::Nested(err) => err.span(),
+        }
+    }
+}
+
+impl Display for TryIntoParamValError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            TryIntoParamValError::UnexpectedType {
+                expected, found, ..
+            } => write!(
+                f,
+                "Expected a value of type `{}` but found {}.",
+                expected, found
+            ),
+            TryIntoParamValError::OutOfRange { value, ty, .. } => {
+                write!(f, "{} does not fit in {}", value, ty)
+            }
+            TryIntoParamValError::SuffixMismatch { suffix, ty, .. } => write!(
+                f,
+                "Expected a {} literal but found a {} literal",
+                ty, suffix
+            ),
+            TryIntoParamValError::Nested(err) => err.fmt(f),
+        }
+    }
+}
+
+impl Error for TryIntoParamValError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            TryIntoParamValError::Nested(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<TryIntoParamValError> for ParseError {
+    fn from(src: TryIntoParamValError) -> ParseError {
+        match src {
+            TryIntoParamValError::Nested(err) => err,
+            other => ParseError::new(other.span(), other),
+        }
+    }
+}
+
+/// The name of the type `T` without the paths of the types it refers to (e.g.
+/// `Vec<String>` rather than `alloc::vec::Vec<alloc::string::String>`).
+pub(crate) fn short_type_name<T>() -> String {
+    let mut name = String::new();
+    let mut path = String::new();
+    for c in type_name::<T>().chars() {
+        if c.is_alphanumeric() || c == '_' || c == ':' {
+            path.push(c);
+        } else {
+            name.push_str(path.rsplit("::").next().unwrap_or_default());
+            path.clear();
+            name.push(c);
+        }
+    }
+
+    name.push_str(path.rsplit("::").next().unwrap_or_default());
+    name
 }
 
-#[metafor(variant = [
-    { name: Bool, ty: bool },
-    { name: Float, ty: f64 },
-    { name: Int, ty: i64 },
-    { name: Str, ty: String }
-])]
-impl TryFrom<ParamVal> for __variant__ty__ {
+impl From<ParseError> for TryIntoParamValError {
+    fn from(src: ParseError) -> Self {
+        TryIntoParamValError::Nested(src)
+    }
+}
+
+impl TryFrom<ParamVal> for bool {
+    type Error = TryIntoParamValError;
+
+    fn try_from(param_val: ParamVal) -> Result<Self, Self::Error> {
+        if let ParamVal::Bool(lit) = param_val {
+            Ok(lit.value)
+        } else {
+            Err(TryIntoParamValError::unexpected_type::<Self>(&param_val))
+        }
+    }
+}
+
+#[metafor(ty = [u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize])]
+impl TryFrom<ParamVal> for __ty__ {
+    type Error = TryIntoParamValError;
+
+    fn try_from(param_val: ParamVal) -> Result<Self, Self::Error> {
+        int_from_param_val(&param_val)
+    }
+}
+
+impl TryFrom<ParamVal> for u8 {
     type Error = TryIntoParamValError;
 
     fn try_from(param_val: ParamVal) -> Result<Self, Self::Error> {
-        if let ParamVal::__variant__name__(b) = param_val {
-            Ok(b)
+        if let ParamVal::Byte(lit) = &param_val {
+            Ok(lit.value())
         } else {
-            Err(TryIntoParamValError::UnexpectedType)
+            int_from_param_val(&param_val)
+        }
+    }
+}
+
+/// Converts a `ParamVal::Int` into the integer type `T`, checking the literal's
+/// suffix and that its value is within the range of `T`.
+fn int_from_param_val<T>(param_val: &ParamVal) -> Result<T, TryIntoParamValError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    if let ParamVal::Int(lit) = param_val {
+        check_suffix::<T>(lit.suffix(), lit.span())?;
+        lit.base10_parse()
+            .map_err(|_| TryIntoParamValError::OutOfRange {
+                span: lit.span(),
+                value: lit.base10_digits().into(),
+                ty: type_name::<T>(),
+            })
+    } else {
+        Err(TryIntoParamValError::unexpected_type::<T>(param_val))
+    }
+}
+
+#[metafor(ty = [f32, f64])]
+impl TryFrom<ParamVal> for __ty__ {
+    type Error = TryIntoParamValError;
+
+    fn try_from(param_val: ParamVal) -> Result<Self, Self::Error> {
+        if let ParamVal::Float(lit) = &param_val {
+            check_suffix::<Self>(lit.suffix(), lit.span())?;
+            match lit.base10_parse::<Self>() {
+                Ok(value) if value.is_finite() => Ok(value),
+                _ => Err(TryIntoParamValError::OutOfRange {
+                    span: lit.span(),
+                    value: lit.base10_digits().into(),
+                    ty: type_name::<Self>(),
+                }),
+            }
+        } else {
+            Err(TryIntoParamValError::unexpected_type::<Self>(&param_val))
+        }
+    }
+}
+
+/// Checks that a numeric literal is either unsuffixed or has a suffix matching
+/// the type `T` it is being converted into.
+fn check_suffix<T>(suffix: &str, span: Span2) -> Result<(), TryIntoParamValError> {
+    let ty = type_name::<T>();
+    if suffix.is_empty() || suffix == ty {
+        Ok(())
+    } else {
+        Err(TryIntoParamValError::SuffixMismatch {
+            span,
+            suffix: suffix.into(),
+            ty,
+        })
+    }
+}
+
+impl TryFrom<ParamVal> for char {
+    type Error = TryIntoParamValError;
+
+    fn try_from(param_val: ParamVal) -> Result<Self, Self::Error> {
+        if let ParamVal::Char(lit) = param_val {
+            Ok(lit.value())
+        } else {
+            Err(TryIntoParamValError::unexpected_type::<Self>(&param_val))
+        }
+    }
+}
+
+impl TryFrom<ParamVal> for String {
+    type Error = TryIntoParamValError;
+
+    fn try_from(param_val: ParamVal) -> Result<Self, Self::Error> {
+        match &param_val {
+            ParamVal::Str(lit) => Ok(lit.value()),
+            ParamVal::Path(path) => {
+                let mut string = String::new();
+                if path.leading_colon.is_some() {
+                    string.push_str("::");
+                }
+
+                for (i, segment) in path.segments.iter().enumerate() {
+                    if !segment.arguments.is_empty() {
+                        return Err(TryIntoParamValError::unexpected_type::<Self>(&param_val));
+                    }
+
+                    if i > 0 {
+                        string.push_str("::");
+                    }
+
+                    string.push_str(&segment.ident.unraw().to_string());
+                }
+
+                Ok(string)
+            }
+            _ => Err(TryIntoParamValError::unexpected_type::<Self>(&param_val)),
+        }
+    }
+}
+
+impl TryFrom<ParamVal> for Path {
+    type Error = TryIntoParamValError;
+
+    fn try_from(param_val: ParamVal) -> Result<Self, Self::Error> {
+        if let ParamVal::Path(path) = param_val {
+            Ok(path)
+        } else {
+            Err(TryIntoParamValError::unexpected_type::<Self>(&param_val))
+        }
+    }
+}
+
+impl TryFrom<ParamVal> for Type {
+    type Error = TryIntoParamValError;
+
+    /// Any value which is valid type syntax (e.g. a path or a tuple) is
+    /// accepted in addition to raw types.
+    fn try_from(param_val: ParamVal) -> Result<Self, Self::Error> {
+        match param_val {
+            ParamVal::Type(ty) => Ok(*ty),
+            ParamVal::Path(path) => Ok(Type::Path(TypePath { qself: None, path })),
+            other => syn::parse2(other.to_token_stream())
+                .map_err(|_| TryIntoParamValError::unexpected_type::<Self>(&other)),
+        }
+    }
+}
+
+impl TryFrom<ParamVal> for Expr {
+    type Error = TryIntoParamValError;
+
+    /// Any value which is valid expression syntax (e.g. a literal or a path) is
+    /// accepted in addition to raw expressions.
+    fn try_from(param_val: ParamVal) -> Result<Self, Self::Error> {
+        match param_val {
+            ParamVal::Expr(expr) => Ok(*expr),
+            other => syn::parse2(other.to_token_stream())
+                .map_err(|_| TryIntoParamValError::unexpected_type::<Self>(&other)),
+        }
+    }
+}
+
+impl TryFrom<ParamVal> for Ident {
+    type Error = TryIntoParamValError;
+
+    fn try_from(param_val: ParamVal) -> Result<Self, Self::Error> {
+        match &param_val {
+            ParamVal::Path(path) => path
+                .get_ident()
+                .cloned()
+                .ok_or_else(|| TryIntoParamValError::unexpected_type::<Self>(&param_val)),
+            _ => Err(TryIntoParamValError::unexpected_type::<Self>(&param_val)),
         }
     }
 }
@@ -35,14 +341,125 @@ where
 {
     type Error = TryIntoParamValError;
 
+    /// Converts each element of an array. The bytes of a byte string literal
+    /// (e.g. `b"bytes"`) are converted as individual integers.
     fn try_from(value: ParamVal) -> Result<Self, Self::Error> {
-        if let ParamVal::Array(array) = value {
-            array
+        match value {
+            ParamVal::Array(array) => array
+                .elems
                 .into_iter()
                 .map(|sub_val| sub_val.try_into())
-                .collect()
+                .collect(),
+            ParamVal::ByteStr(lit) => lit
+                .value()
+                .into_iter()
+                .map(|byte| {
+                    let mut token = Literal::u8_unsuffixed(byte);
+                    token.set_span(lit.span());
+                    ParamVal::Int(token.into()).try_into()
+                })
+                .collect(),
+            _ => Err(TryIntoParamValError::unexpected_type::<Self>(&value)),
+        }
+    }
+}
+
+impl<K, V> TryFrom<ParamVal> for HashMap<K, V>
+where
+    K: TryFrom<ParamVal, Error = TryIntoParamValError> + Eq + Hash,
+    V: TryFrom<ParamVal, Error = TryIntoParamValError>,
+{
+    type Error = TryIntoParamValError;
+
+    fn try_from(param_val: ParamVal) -> Result<Self, Self::Error> {
+        let mut map = HashMap::new();
+        convert_map::<Self, _, _>(param_val, |key, val| map.insert(key, val).is_some())?;
+        Ok(map)
+    }
+}
+
+impl<K, V> TryFrom<ParamVal> for BTreeMap<K, V>
+where
+    K: TryFrom<ParamVal, Error = TryIntoParamValError> + Ord,
+    V: TryFrom<ParamVal, Error = TryIntoParamValError>,
+{
+    type Error = TryIntoParamValError;
+
+    fn try_from(param_val: ParamVal) -> Result<Self, Self::Error> {
+        let mut map = BTreeMap::new();
+        convert_map::<Self, _, _>(param_val, |key, val| map.insert(key, val).is_some())?;
+        Ok(map)
+    }
+}
+
+/// Converts the entries of a `ParamVal::Map` and passes each of them to
+/// `insert`, which returns `true` if the key was already present. `M` is the
+/// type of map being converted into.
+fn convert_map<M, K, V>(
+    param_val: ParamVal,
+    mut insert: impl FnMut(K, V) -> bool,
+) -> Result<(), TryIntoParamValError>
+where
+    K: TryFrom<ParamVal, Error = TryIntoParamValError>,
+    V: TryFrom<ParamVal, Error = TryIntoParamValError>,
+{
+    if let ParamVal::Map(map) = param_val {
+        for (key, val) in map.entries {
+            let key_span = key.span();
+            if insert(key.try_into()?, val.try_into()?) {
+                return Err(ParseError::new(key_span, "Duplicate key in map.").into());
+            }
+        }
+
+        Ok(())
+    } else {
+        Err(TryIntoParamValError::unexpected_type::<M>(&param_val))
+    }
+}
+
+/// Implements `TryFrom<ParamVal>` for a tuple of the provided length. Both tuples
+/// and arrays of the same length are converted element by element.
+macro_rules! impl_try_from_for_tuple {
+    ($len:literal => $($elem:ident),+) => {
+        impl<$($elem),+> TryFrom<ParamVal> for ($($elem,)+)
+        where
+            $($elem: TryFrom<ParamVal, Error = TryIntoParamValError>,)+
+        {
+            type Error = TryIntoParamValError;
+
+            fn try_from(param_val: ParamVal) -> Result<Self, Self::Error> {
+                match param_val {
+                    ParamVal::Tuple(ParamTuple { elems, .. })
+                    | ParamVal::Array(ParamArray { elems, .. })
+                        if elems.len() == $len =>
+                    {
+                        let mut elems = elems.into_iter();
+                        Ok(($(<$elem>::try_from(elems.next().unwrap())?,)+))
+                    }
+                    _ => Err(TryIntoParamValError::unexpected_type::<Self>(&param_val)),
+                }
+            }
+        }
+    };
+}
+
+impl_try_from_for_tuple!(1 => A);
+impl_try_from_for_tuple!(2 => A, B);
+impl_try_from_for_tuple!(3 => A, B, C);
+impl_try_from_for_tuple!(4 => A, B, C, D);
+impl_try_from_for_tuple!(5 => A, B, C, D, E);
+impl_try_from_for_tuple!(6 => A, B, C, D, E, F);
+impl_try_from_for_tuple!(7 => A, B, C, D, E, F, G);
+impl_try_from_for_tuple!(8 => A, B, C, D, E, F, G, H);
+
+impl TryFrom<ParamVal> for Parameters {
+    type Error = TryIntoParamValError;
+
+    fn try_from(param_val: ParamVal) -> Result<Self, Self::Error> {
+        if let ParamVal::Nested(list) = param_val {
+            Ok(list.params)
         } else {
-            Err(Self::Error::UnexpectedType)
+            Err(TryIntoParamValError::unexpected_type::<Self>(&param_val))
         }
     }
 }
@@ -55,37 +472,239 @@ mod tests {
 
     #[test]
     fn array_conversion() {
-        let left =
-            ParamVal::Array(vec![ParamVal::Int(1), ParamVal::Int(2), ParamVal::Int(3)]).try_into();
-        let right = Ok(vec![1, 2, 3]);
+        let left: Vec<i64> = ParamVal::from(vec![1.into(), 2.into(), 3.into()])
+            .try_into()
+            .unwrap();
+        let right = vec![1, 2, 3];
         assert_eq!(left, right);
     }
 
     #[test]
     fn bool_conversion() {
-        let left = ParamVal::Bool(true).try_into();
-        let right = Ok(true);
+        let left: bool = ParamVal::from(true).try_into().unwrap();
+        let right = true;
         assert_eq!(left, right)
     }
 
     #[test]
     fn float_conversion() {
-        let left: Result<f64, _> = ParamVal::Float(1.0).try_into();
-        let right: Result<f64, _> = Ok(1.0);
-        assert_eq!(left, right)
+        let left: f64 = ParamVal::from(1.0).try_into().unwrap();
+        let right: f64 = 1.0;
+        assert_eq!(left, right);
+
+        let right: ParamVal = syn::parse_quote!(f64::NAN);
+        assert_eq!(ParamVal::from(f64::NAN), right);
+
+        let right: ParamVal = syn::parse_quote!(f32::NEG_INFINITY);
+        assert_eq!(ParamVal::from(f32::NEG_INFINITY), right);
+
+        let left: Result<f64, _> = ParamVal::from(f64::INFINITY).try_into();
+        assert!(left.is_err());
     }
 
     #[test]
     fn int_conversion() {
-        let left: Result<i64, _> = ParamVal::Int(-1).try_into();
-        let right: Result<i64, _> = Ok(-1);
+        let left: i64 = ParamVal::from(-1).try_into().unwrap();
+        let right: i64 = -1;
         assert_eq!(left, right)
     }
 
     #[test]
     fn str_conversion() {
-        let left: Result<String, _> = ParamVal::Str("hello".into()).try_into();
-        let right: Result<String, _> = Ok("hello".into());
+        let left: String = ParamVal::from("hello").try_into().unwrap();
+        let right: String = "hello".into();
         assert_eq!(left, right)
     }
+
+    #[test]
+    fn unexpected_type_conversion() {
+        let left: Result<i64, _> = ParamVal::from("hello").try_into();
+        assert_eq!(
+            left.unwrap_err().to_string(),
+            "Expected a value of type `i64` but found a string."
+        );
+
+        let left: Result<Vec<String>, _> = ParamVal::from(1).try_into();
+        assert_eq!(
+            left.unwrap_err().to_string(),
+            "Expected a value of type `Vec<String>` but found an integer."
+        );
+    }
+
+    #[test]
+    fn nested_conversion() {
+        let mut params: Parameters = syn::parse_quote!(get(path = "/x"));
+        let nested = params.remove(&"get".into()).unwrap();
+        let left: Parameters = nested.try_into().unwrap();
+        assert_eq!(left.get(&"path".into()), Some(&ParamVal::from("/x")));
+    }
+
+    #[test]
+    fn path_conversion() {
+        let path: Path = syn::parse_quote!(my::module::func);
+        let left: Path = ParamVal::Path(path.clone()).try_into().unwrap();
+        assert_eq!(left, path);
+
+        let left: String = ParamVal::Path(path).try_into().unwrap();
+        assert_eq!(left, "my::module::func");
+    }
+
+    #[test]
+    fn ident_conversion() {
+        let left: Ident = ParamVal::Path(syn::parse_quote!(Fast)).try_into().unwrap();
+        let right: Ident = syn::parse_quote!(Fast);
+        assert_eq!(left, right);
+
+        let left: Result<Ident, _> = ParamVal::Path(syn::parse_quote!(a::b)).try_into();
+        assert!(left.is_err());
+    }
+
+    #[test]
+    fn type_conversion() {
+        let left: Type = ParamVal::Type(Box::new(syn::parse_quote!(Vec<u8>)))
+            .try_into()
+            .unwrap();
+        let right: Type = syn::parse_quote!(Vec<u8>);
+        assert_eq!(left, right);
+
+        let left: Type = ParamVal::Path(syn::parse_quote!(u8)).try_into().unwrap();
+        let right: Type = syn::parse_quote!(u8);
+        assert_eq!(left, right);
+
+        let param_val: ParamVal = syn::parse_quote!((u8, u16));
+        assert!(matches!(param_val, ParamVal::Tuple(_)));
+        let left: Type = param_val.try_into().unwrap();
+        let right: Type = syn::parse_quote!((u8, u16));
+        assert_eq!(left, right);
+
+        let param_val: ParamVal = syn::parse_quote!(());
+        let left: Type = param_val.try_into().unwrap();
+        let right: Type = syn::parse_quote!(());
+        assert_eq!(left, right);
+
+        let param_val: ParamVal = syn::parse_quote!([u8]);
+        assert!(matches!(param_val, ParamVal::Array(_)));
+        let left: Type = param_val.try_into().unwrap();
+        let right: Type = syn::parse_quote!([u8]);
+        assert_eq!(left, right);
+
+        let left: Result<Type, _> = ParamVal::from(5).try_into();
+        assert!(left.is_err());
+    }
+
+    #[test]
+    fn expr_conversion() {
+        let expr: Expr = syn::parse_quote!(Duration::from_secs(5));
+        let left: Expr = ParamVal::Expr(Box::new(expr.clone())).try_into().unwrap();
+        assert_eq!(left, expr);
+
+        let left: Expr = ParamVal::from(5).try_into().unwrap();
+        let right: Expr = syn::parse_quote!(5);
+        assert_eq!(left, right);
+
+        let left: Result<Expr, _> = ParamVal::Type(Box::new(syn::parse_quote!(Vec<u8>))).try_into();
+        assert!(left.is_err());
+    }
+
+    #[test]
+    fn map_conversion() {
+        let val: ParamVal = syn::parse_quote!({ "Accept": "json", "X-Id": "1" });
+
+        let left: HashMap<String, String> = val.clone().try_into().unwrap();
+        let mut right = HashMap::new();
+        right.insert("Accept".to_string(), "json".to_string());
+        right.insert("X-Id".to_string(), "1".to_string());
+        assert_eq!(left, right);
+
+        let left: BTreeMap<String, String> = val.try_into().unwrap();
+        assert_eq!(left, right.into_iter().collect());
+    }
+
+    #[test]
+    fn map_duplicate_key() {
+        let val: ParamVal = syn::parse_quote!({ a: 1, a: 2 });
+        let left: Result<BTreeMap<String, i64>, _> = val.try_into();
+        assert!(left.is_err());
+    }
+
+    #[test]
+    fn tuple_conversion() {
+        let val: ParamVal = syn::parse_quote!([("a", 1), ("b", 2)]);
+        let left: Vec<(String, i64)> = val.try_into().unwrap();
+        let right = vec![("a".to_string(), 1), ("b".to_string(), 2)];
+        assert_eq!(left, right);
+
+        let val: ParamVal = syn::parse_quote!(["a", 1, true]);
+        let left: (String, i64, bool) = val.try_into().unwrap();
+        assert_eq!(left, ("a".to_string(), 1, true));
+
+        let val: ParamVal = syn::parse_quote!(("a", 1));
+        let left: Result<(String, i64, bool), _> = val.try_into();
+        assert!(left.is_err());
+    }
+
+    #[test]
+    fn sized_int_conversion() {
+        let left: u8 = ParamVal::from(255).try_into().unwrap();
+        assert_eq!(left, 255);
+
+        let val: ParamVal = syn::parse_quote!(18446744073709551616);
+        let left: u128 = val.try_into().unwrap();
+        assert_eq!(left, 18_446_744_073_709_551_616);
+
+        let left: i8 = ParamVal::from(-128).try_into().unwrap();
+        assert_eq!(left, -128);
+    }
+
+    #[test]
+    fn out_of_range_conversion() {
+        let left: Result<u8, _> = ParamVal::from(300).try_into();
+        let err: ParseError = left.unwrap_err().into();
+        assert_eq!(err.to_string(), "300 does not fit in u8");
+
+        let val: ParamVal = syn::parse_quote!(-1);
+        let left: Result<u32, _> = val.try_into();
+        let err: ParseError = left.unwrap_err().into();
+        assert_eq!(err.to_string(), "-1 does not fit in u32");
+
+        let left: Result<f32, _> = ParamVal::from(1e300).try_into();
+        assert!(left.is_err());
+    }
+
+    #[test]
+    fn suffixed_conversion() {
+        let val: ParamVal = syn::parse_quote!(10u8);
+        let left: u8 = val.try_into().unwrap();
+        assert_eq!(left, 10);
+
+        let val: ParamVal = syn::parse_quote!(10u16);
+        let left: Result<u8, _> = val.try_into();
+        let err: ParseError = left.unwrap_err().into();
+        assert_eq!(
+            err.to_string(),
+            "Expected a u8 literal but found a u16 literal"
+        );
+
+        let val: ParamVal = syn::parse_quote!(1.5f32);
+        let left: Result<f64, _> = val.clone().try_into();
+        assert!(left.is_err());
+        let left: f32 = val.try_into().unwrap();
+        assert_eq!(left, 1.5);
+    }
+
+    #[test]
+    fn char_and_byte_conversion() {
+        let left: char = ParamVal::Char(syn::parse_quote!(',')).try_into().unwrap();
+        assert_eq!(left, ',');
+
+        let left: u8 = ParamVal::Byte(syn::parse_quote!(b'x')).try_into().unwrap();
+        assert_eq!(left, b'x');
+
+        let val = ParamVal::ByteStr(syn::parse_quote!(b"\x89PNG"));
+        let left: Vec<u8> = val.try_into().unwrap();
+        assert_eq!(left, b"\x89PNG".to_vec());
+
+        let left: Result<u16, _> = ParamVal::Byte(syn::parse_quote!(b'x')).try_into();
+        assert!(left.is_err());
+    }
 }
@@ -1,21 +1,182 @@
+use crate::params::ParamArray;
+use crate::params::ParamMap;
+use crate::params::ParamTuple;
 use crate::ParamVal;
 use core::convert::TryFrom;
 use core::convert::TryInto;
+use proc_macro2::Literal;
+use proc_macro2::Span as Span2;
+use syn::parse::discouraged::Speculative;
 use syn::parse::Error as ParseError;
 use syn::parse::Parse;
 use syn::parse::ParseStream;
 use syn::parse::Result as ParseResult;
+use syn::punctuated::Punctuated;
+use syn::token::Brace;
+use syn::token::Bracket;
+use syn::token::Paren;
 use syn::Expr;
 use syn::ExprArray;
 use syn::ExprLit;
+use syn::ExprPath;
+use syn::ExprTuple;
 use syn::ExprUnary;
 use syn::Lit;
+use syn::Path;
+use syn::Token;
+use syn::Type;
 use syn::UnOp;
 
 impl Parse for ParamVal {
+    /// Parses a value as an expression. Values which are not valid expressions
+    /// are parsed as a type (e.g. `Vec<u8>`), while expressions which are not
+    /// one of the recognized kinds of values are retained as a raw
+    /// `ParamVal::Expr`.
     fn parse(input: ParseStream) -> ParseResult<Self> {
-        input.parse::<Expr>().and_then(TryInto::try_into)
+        if input.peek(Brace) && is_map(input) {
+            return parse_map(input);
+        }
+
+        if input.peek(Bracket) {
+            let array_fork = input.fork();
+            if let Ok(val) = parse_array(&array_fork) {
+                if is_value_end(&array_fork) {
+                    input.advance_to(&array_fork);
+                    return Ok(val);
+                }
+            }
+        }
+
+        if input.peek(Paren) {
+            let tuple_fork = input.fork();
+            if let Ok(Some(val)) = parse_tuple(&tuple_fork) {
+                if is_value_end(&tuple_fork) {
+                    input.advance_to(&tuple_fork);
+                    return Ok(val);
+                }
+            }
+        }
+
+        let expr_fork = input.fork();
+        let expr_val = expr_fork
+            .parse::<Expr>()
+            .and_then(ParamVal::try_from)
+            .and_then(|val| {
+                if is_value_end(&expr_fork) {
+                    Ok(val)
+                } else {
+                    Err(expr_fork.error("Unexpected tokens in parameter value"))
+                }
+            });
+
+        // A type is only preferred when the value isn't an expression or when the
+        // expression stops short of the type (e.g. `HashMap<K, V>` is parsed as
+        // the expression `HashMap < K`)
+        let type_fork = input.fork();
+        if let Ok(ty) = type_fork.parse::<Type>() {
+            let is_longer = || remaining_tokens(&type_fork) < remaining_tokens(&expr_fork);
+            if is_value_end(&type_fork) && (expr_val.is_err() || is_longer()) {
+                input.advance_to(&type_fork);
+                return Ok(ParamVal::Type(Box::new(ty)));
+            }
+        }
+
+        let val = expr_val?;
+        input.advance_to(&expr_fork);
+        Ok(val)
+    }
+}
+
+/// Counts the token trees which remain in the input.
+fn remaining_tokens(input: ParseStream) -> usize {
+    let mut cursor = input.cursor();
+    let mut count = 0;
+    while let Some((_, next)) = cursor.token_tree() {
+        cursor = next;
+        count += 1;
+    }
+
+    count
+}
+
+/// Parses a bracketed array of values (e.g. `[1, 2, 3]`)
+fn parse_array(input: ParseStream) -> ParseResult<ParamVal> {
+    let content;
+    let bracket_token = syn::bracketed!(content in input);
+    let elems = Punctuated::<ParamVal, Token![,]>::parse_terminated(&content)?;
+    Ok(ParamVal::Array(ParamArray {
+        bracket_token,
+        elems: elems.into_iter().collect(),
+    }))
+}
+
+/// Parses a parenthesized tuple of values (e.g. `("a", 1)`). A single value
+/// without a trailing comma is not a tuple, in which case `None` is returned.
+fn parse_tuple(input: ParseStream) -> ParseResult<Option<ParamVal>> {
+    let content;
+    let paren_token = syn::parenthesized!(content in input);
+    let elems = Punctuated::<ParamVal, Token![,]>::parse_terminated(&content)?;
+    if elems.len() == 1 && !elems.trailing_punct() {
+        return Ok(None);
+    }
+
+    Ok(Some(ParamVal::Tuple(ParamTuple {
+        paren_token,
+        elems: elems.into_iter().collect(),
+    })))
+}
+
+/// Checks if the braces at the start of the input contain a map rather than a
+/// block expression (i.e. they are empty or begin with `key:`).
+fn is_map(input: ParseStream) -> bool {
+    starts_map(&input.fork()).unwrap_or(false)
+}
+
+fn starts_map(input: ParseStream) -> ParseResult<bool> {
+    let content;
+    syn::braced!(content in input);
+    Ok(content.is_empty()
+        || (parse_map_key(&content).is_ok()
+            && content.peek(Token![:])
+            && !content.peek(Token![::])))
+}
+
+/// Parses a braced map of values (e.g. `{ "Accept": "json" }`)
+fn parse_map(input: ParseStream) -> ParseResult<ParamVal> {
+    let content;
+    let brace_token = syn::braced!(content in input);
+    let mut entries = Vec::new();
+    while !content.is_empty() {
+        let key = parse_map_key(&content)?;
+        content.parse::<Token![:]>()?;
+        let val = content.parse()?;
+        entries.push((key, val));
+
+        if content.is_empty() {
+            break;
+        }
+
+        content.parse::<Token![,]>()?;
     }
+
+    Ok(ParamVal::Map(ParamMap {
+        brace_token,
+        entries,
+    }))
+}
+
+/// Parses the key of a map entry, which is either a literal or a path.
+fn parse_map_key(input: ParseStream) -> ParseResult<ParamVal> {
+    if input.peek(Lit) {
+        input.parse::<Lit>().and_then(ParamVal::try_from)
+    } else {
+        Path::parse_mod_style(input).map(ParamVal::Path)
+    }
+}
+
+/// Checks if the input is positioned at the end of a parameter value.
+fn is_value_end(input: ParseStream) -> bool {
+    input.is_empty() || input.peek(Token![,])
 }
 
 impl TryFrom<Expr> for ParamVal {
@@ -23,25 +184,74 @@ impl TryFrom<Expr> for ParamVal {
     fn try_from(value: Expr) -> Result<Self, Self::Error> {
         match value {
             Expr::Array(expr_array) => expr_array.try_into(),
-            Expr::Lit(expr_lit) => expr_lit.try_into(),
-            Expr::Unary(expr_unary) => expr_unary.try_into(),
-            other_expr => Err(ParseError::new_spanned(
-                other_expr,
-                "Unsupported expression",
-            )),
+            Expr::Tuple(expr_tuple) => expr_tuple.try_into(),
+            Expr::Paren(expr_paren) => (*expr_paren.expr).try_into(),
+            Expr::Lit(expr_lit) if is_value_lit(&expr_lit.lit) => expr_lit.try_into(),
+            Expr::Unary(expr_unary) if is_negated_number(&expr_unary) => expr_unary.try_into(),
+            Expr::Path(expr_path) if expr_path.qself.is_none() => expr_path.try_into(),
+            other_expr => Ok(ParamVal::Expr(Box::new(other_expr))),
+        }
+    }
+}
+
+/// Checks if the literal is one that has a dedicated `ParamVal` variant.
+fn is_value_lit(lit: &Lit) -> bool {
+    matches!(
+        lit,
+        Lit::Bool(_)
+            | Lit::Int(_)
+            | Lit::Float(_)
+            | Lit::Str(_)
+            | Lit::Char(_)
+            | Lit::Byte(_)
+            | Lit::ByteStr(_)
+    )
+}
+
+/// Checks if the expression is the negation of a numeric literal (e.g. `-1`).
+fn is_negated_number(expr_unary: &ExprUnary) -> bool {
+    match (&expr_unary.op, &*expr_unary.expr) {
+        (UnOp::Neg(_), Expr::Lit(ExprLit { lit, .. })) => {
+            matches!(lit, Lit::Int(_) | Lit::Float(_))
         }
+        _ => false,
     }
 }
 
 impl TryFrom<ExprArray> for ParamVal {
     type Error = ParseError;
-    fn try_from(expr_array: ExprArray) -> Result<Self, Self::Error> {
-        expr_array
-            .elems
+    fn try_from(
+        ExprArray {
+            bracket_token,
+            elems,
+            ..
+        }: ExprArray,
+    ) -> Result<Self, Self::Error> {
+        elems
             .into_iter()
             .map(ParamVal::try_from)
             .collect::<Result<Vec<ParamVal>, _>>()
-            .map(ParamVal::Array)
+            .map(|elems| {
+                ParamVal::Array(ParamArray {
+                    bracket_token,
+                    elems,
+                })
+            })
+    }
+}
+
+impl TryFrom<ExprTuple> for ParamVal {
+    type Error = ParseError;
+    fn try_from(
+        ExprTuple {
+            paren_token, elems, ..
+        }: ExprTuple,
+    ) -> Result<Self, Self::Error> {
+        elems
+            .into_iter()
+            .map(ParamVal::try_from)
+            .collect::<Result<Vec<ParamVal>, _>>()
+            .map(|elems| ParamVal::Tuple(ParamTuple { paren_token, elems }))
     }
 }
 
@@ -52,14 +262,31 @@ impl TryFrom<ExprLit> for ParamVal {
     }
 }
 
+impl TryFrom<ExprPath> for ParamVal {
+    type Error = ParseError;
+    fn try_from(ExprPath { qself, path, .. }: ExprPath) -> Result<Self, Self::Error> {
+        match qself {
+            Some(qself) => Err(ParseError::new(
+                qself.lt_token.span,
+                "Qualified paths are not supported",
+            )),
+            None => Ok(ParamVal::Path(path)),
+        }
+    }
+}
+
 impl TryFrom<ExprUnary> for ParamVal {
     type Error = ParseError;
     fn try_from(ExprUnary { expr, op, .. }: ExprUnary) -> Result<Self, Self::Error> {
         match op {
             UnOp::Neg(_) => match *expr {
                 Expr::Lit(ExprLit { lit, .. }) => match lit {
-                    Lit::Int(i) => i.base10_parse::<i64>().map(|int| ParamVal::Int(-int)),
-                    Lit::Float(f) => f.base10_parse::<f64>().map(|float| ParamVal::Float(-float)),
+                    Lit::Int(i) => {
+                        negate(i.base10_digits(), i.suffix(), i.span()).map(ParamVal::Int)
+                    }
+                    Lit::Float(f) => {
+                        negate(f.base10_digits(), f.suffix(), f.span()).map(ParamVal::Float)
+                    }
                     other_neg_lit => Err(ParseError::new_spanned(
                         other_neg_lit,
                         "Non-negatable literal",
@@ -78,14 +305,28 @@ impl TryFrom<ExprUnary> for ParamVal {
     }
 }
 
+/// Creates the literal for the negation of a numeric literal, retaining its
+/// suffix and span.
+fn negate<T: From<Literal>>(digits: &str, suffix: &str, span: Span2) -> ParseResult<T> {
+    let repr = format!("-{}{}", digits, suffix);
+    let mut token: Literal = repr
+        .parse()
+        .map_err(|_| ParseError::new(span, "Non-negatable literal"))?;
+    token.set_span(span);
+    Ok(token.into())
+}
+
 impl TryFrom<Lit> for ParamVal {
     type Error = ParseError;
     fn try_from(lit: Lit) -> Result<Self, Self::Error> {
         match lit {
-            Lit::Bool(b) => Ok(ParamVal::Bool(b.value)),
-            Lit::Int(i) => i.base10_parse::<i64>().map(ParamVal::Int),
-            Lit::Float(f) => f.base10_parse::<f64>().map(ParamVal::Float),
-            Lit::Str(s) => Ok(ParamVal::Str(s.value())),
+            Lit::Bool(b) => Ok(ParamVal::Bool(b)),
+            Lit::Int(i) => Ok(ParamVal::Int(i)),
+            Lit::Float(f) => Ok(ParamVal::Float(f)),
+            Lit::Str(s) => Ok(ParamVal::Str(s)),
+            Lit::Char(c) => Ok(ParamVal::Char(c)),
+            Lit::Byte(b) => Ok(ParamVal::Byte(b)),
+            Lit::ByteStr(b) => Ok(ParamVal::ByteStr(b)),
             other_lit => Err(ParseError::new_spanned(other_lit, "Unrecognized literal")),
         }
     }
@@ -95,6 +336,7 @@ impl TryFrom<Lit> for ParamVal {
 mod tests {
     use super::*;
     use pretty_assertions::assert_eq;
+    use quote::ToTokens;
     use syn::parse_quote;
 
     #[test]
@@ -102,38 +344,38 @@ mod tests {
         let array_val: ParamVal = parse_quote!([1, 2, 3]);
         assert_eq!(
             array_val,
-            ParamVal::Array(vec![ParamVal::Int(1), ParamVal::Int(2), ParamVal::Int(3)])
+            ParamVal::from(vec![1.into(), 2.into(), 3.into()])
         )
     }
 
     #[test]
     fn parse_bool() {
         let bool_val: ParamVal = parse_quote!(true);
-        assert_eq!(bool_val, ParamVal::Bool(true));
+        assert_eq!(bool_val, ParamVal::from(true));
     }
 
     #[test]
     fn parse_unsigned_float() {
         let float_val: ParamVal = parse_quote!(1.0);
-        assert_eq!(float_val, ParamVal::Float(1.0));
+        assert_eq!(float_val, ParamVal::Float(parse_quote!(1.0)));
     }
 
     #[test]
     fn parse_signed_float() {
         let float_val: ParamVal = parse_quote!(-1.0);
-        assert_eq!(float_val, ParamVal::Float(-1.0));
+        assert_eq!(float_val, ParamVal::Float(parse_quote!(-1.0)));
     }
 
     #[test]
     fn parse_unsigned_int() {
         let int_val: ParamVal = parse_quote!(1);
-        assert_eq!(int_val, ParamVal::Int(1));
+        assert_eq!(int_val, ParamVal::from(1));
     }
 
     #[test]
     fn parse_signed_int() {
         let int_val: ParamVal = parse_quote!(-1);
-        assert_eq!(int_val, ParamVal::Int(-1));
+        assert_eq!(int_val, ParamVal::from(-1));
     }
 
     #[test]
@@ -141,4 +383,146 @@ mod tests {
         let str_val: ParamVal = parse_quote!("foo");
         assert_eq!(str_val, "foo".into())
     }
+
+    #[test]
+    fn reparse_tokens() {
+        let array_val: ParamVal = parse_quote!([1, -2, "three"]);
+        let reparsed: ParamVal = parse_quote!(#array_val);
+        assert_eq!(array_val, reparsed);
+    }
+
+    #[test]
+    fn parse_path() {
+        let path_val: ParamVal = parse_quote!(my::module::func);
+        assert_eq!(path_val, ParamVal::Path(parse_quote!(my::module::func)));
+    }
+
+    #[test]
+    fn parse_path_array() {
+        let array_val: ParamVal = parse_quote!([Clone, Debug]);
+        assert_eq!(
+            array_val,
+            ParamVal::from(vec![
+                ParamVal::Path(parse_quote!(Clone)),
+                ParamVal::Path(parse_quote!(Debug))
+            ])
+        );
+    }
+
+    #[test]
+    fn parse_type() {
+        let type_val: ParamVal = parse_quote!(Vec<u8>);
+        assert_eq!(type_val, ParamVal::Type(Box::new(parse_quote!(Vec<u8>))));
+
+        let type_val: ParamVal = parse_quote!(HashMap<K, V>);
+        assert_eq!(
+            type_val,
+            ParamVal::Type(Box::new(parse_quote!(HashMap<K, V>)))
+        );
+
+        let type_val: ParamVal = parse_quote!(&'static str);
+        assert_eq!(
+            type_val,
+            ParamVal::Type(Box::new(parse_quote!(&'static str)))
+        );
+    }
+
+    #[test]
+    fn parse_expr() {
+        let expr_val: ParamVal = parse_quote!(Duration::from_secs(5));
+        assert_eq!(
+            expr_val,
+            ParamVal::Expr(Box::new(parse_quote!(Duration::from_secs(5))))
+        );
+
+        let expr_val: ParamVal = parse_quote!(|e| e.is_transient());
+        assert_eq!(
+            expr_val,
+            ParamVal::Expr(Box::new(parse_quote!(|e| e.is_transient())))
+        );
+
+        let expr_val: ParamVal = parse_quote!(a < b);
+        assert_eq!(expr_val, ParamVal::Expr(Box::new(parse_quote!(a < b))));
+
+        for value in &["f(x)", "&X", "[u8; 4]"] {
+            let val: ParamVal = syn::parse_str(value).unwrap();
+            assert!(matches!(val, ParamVal::Expr(_)), "{} is {:?}", value, val);
+        }
+    }
+
+    #[test]
+    fn parse_map() {
+        let map_val: ParamVal = parse_quote!({ "Accept": "json", "X-Id": 1 });
+        match map_val {
+            ParamVal::Map(map) => assert_eq!(
+                map.entries,
+                vec![("Accept".into(), "json".into()), ("X-Id".into(), 1.into())]
+            ),
+            _ => panic!("Expected a map"),
+        }
+
+        let block_val: ParamVal = parse_quote!({ compute() });
+        assert_eq!(
+            block_val,
+            ParamVal::Expr(Box::new(parse_quote!({ compute() })))
+        );
+    }
+
+    #[test]
+    fn parse_map_array() {
+        let array_val: ParamVal = parse_quote!([{ a: 1 }, {}]);
+        let reparsed: ParamVal = parse_quote!(#array_val);
+        assert_eq!(array_val, reparsed);
+        assert!(matches!(array_val, ParamVal::Array(array) if array.elems.len() == 2));
+    }
+
+    #[test]
+    fn parse_tuple() {
+        let tuple_val: ParamVal = parse_quote!(("a", 1));
+        let reparsed: ParamVal = parse_quote!(#tuple_val);
+        assert_eq!(tuple_val, reparsed);
+        assert!(matches!(tuple_val, ParamVal::Tuple(tuple) if tuple.elems.len() == 2));
+
+        let tuple_val: ParamVal = parse_quote!((1,));
+        assert!(matches!(tuple_val, ParamVal::Tuple(tuple) if tuple.elems.len() == 1));
+
+        let paren_val: ParamVal = parse_quote!((1));
+        assert_eq!(paren_val, ParamVal::from(1));
+    }
+
+    #[test]
+    fn parse_large_int() {
+        let int_val: ParamVal = parse_quote!(18446744073709551615);
+        assert_eq!(int_val, ParamVal::from(u64::MAX));
+
+        let int_val: ParamVal = parse_quote!(-9223372036854775808);
+        assert_eq!(int_val, ParamVal::from(i64::MIN));
+    }
+
+    #[test]
+    fn reparse_suffixed() {
+        let int_val: ParamVal = parse_quote!(-10i128);
+        let reparsed: ParamVal = parse_quote!(#int_val);
+        assert_eq!(int_val, reparsed);
+        assert!(matches!(reparsed, ParamVal::Int(lit) if lit.suffix() == "i128"));
+
+        let int_val: ParamVal = parse_quote!(-170141183460469231731687303715884105728);
+        let reparsed: ParamVal = parse_quote!(#int_val);
+        assert_eq!(int_val, reparsed);
+
+        let float_val: ParamVal = parse_quote!(2.5f32);
+        assert_eq!(float_val.to_token_stream().to_string(), "2.5f32");
+    }
+
+    #[test]
+    fn parse_char_and_bytes() {
+        let char_val: ParamVal = parse_quote!(',');
+        assert_eq!(char_val, ParamVal::Char(parse_quote!(',')));
+
+        let byte_val: ParamVal = parse_quote!(b'x');
+        assert_eq!(byte_val, ParamVal::Byte(parse_quote!(b'x')));
+
+        let byte_str_val: ParamVal = parse_quote!(b"bytes");
+        assert_eq!(byte_str_val, ParamVal::ByteStr(parse_quote!(b"bytes")));
+    }
 }
@@ -0,0 +1,128 @@
+use crate::ParamVal;
+use crate::TryIntoParamValError;
+use core::convert::TryFrom;
+use core::ops::Deref;
+use proc_macro2::Delimiter;
+use proc_macro2::Group;
+use proc_macro2::TokenStream as TokenStream2;
+use proc_macro2::TokenTree;
+use syn::parse::Parse;
+use syn::parse::ParseStream;
+use syn::parse::Parser;
+use syn::parse::Result as ParseResult;
+use syn::LitStr;
+
+/// A parameter value that contains Rust syntax inside of a string literal (e.g.
+/// the `"T: Clone"` in `bound = "T: Clone"`). The contents of the string are
+/// parsed into `T` and the original literal is retained for its span.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParseStr<T> {
+    lit: LitStr,
+    value: T,
+}
+
+impl<T> ParseStr<T> {
+    /// The string literal the value was parsed from.
+    pub fn lit(&self) -> &LitStr {
+        &self.lit
+    }
+
+    /// Consumes the wrapper and returns the parsed value.
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T> Deref for ParseStr<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T> TryFrom<ParamVal> for ParseStr<T>
+where
+    T: Parse,
+{
+    type Error = TryIntoParamValError;
+
+    fn try_from(param_val: ParamVal) -> Result<Self, Self::Error> {
+        if let ParamVal::Str(lit) = param_val {
+            let value = parse_lit(&lit)?;
+            Ok(ParseStr { lit, value })
+        } else {
+            Err(TryIntoParamValError::unexpected_type::<Self>(&param_val))
+        }
+    }
+}
+
+/// Parses the contents of the literal as `T`. The contents are parsed within a
+/// group located at the literal so that every error, including an unexpected
+/// end of input, is reported at the literal rather than the call site.
+fn parse_lit<T: Parse>(lit: &LitStr) -> ParseResult<T> {
+    let tokens: TokenStream2 = lit.parse()?;
+    let mut group = Group::new(Delimiter::Parenthesis, tokens);
+    group.set_span(lit.span());
+
+    let parser = |input: ParseStream| {
+        let content;
+        syn::parenthesized!(content in input);
+        let value = content.parse()?;
+        if content.is_empty() {
+            Ok(value)
+        } else {
+            Err(content.error("unexpected token"))
+        }
+    };
+
+    parser.parse2(TokenTree::Group(group).into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::convert::TryInto;
+    use pretty_assertions::assert_eq;
+    use syn::parse_quote;
+    use syn::spanned::Spanned;
+    use syn::Path;
+    use syn::WherePredicate;
+
+    #[test]
+    fn parse_str_conversion() {
+        let left: ParseStr<WherePredicate> = ParamVal::from("T: Clone").try_into().unwrap();
+        let right: WherePredicate = parse_quote!(T: Clone);
+        assert_eq!(left.into_inner(), right);
+
+        let left: ParseStr<Path> = ParamVal::from("my::module").try_into().unwrap();
+        assert_eq!(left.lit().value(), "my::module");
+        assert_eq!(*left, parse_quote!(my::module));
+    }
+
+    #[test]
+    fn parse_str_syntax_error() {
+        // The error is reported within the literal rather than at the call site
+        let param_val: ParamVal = syn::parse_str(r#"    "my::""#).unwrap();
+        let lit_span = param_val.span();
+        let left: Result<ParseStr<Path>, _> = param_val.try_into();
+        let err = match left {
+            Err(TryIntoParamValError::Nested(err)) => err,
+            other => panic!("Expected a nested error but found {:?}", other),
+        };
+
+        let (start, end) = (err.span().start(), err.span().end());
+        assert_eq!((start.line, end.line), (1, 1));
+        assert!(start.column >= lit_span.start().column);
+        assert!(end.column <= lit_span.end().column);
+    }
+
+    #[test]
+    fn parse_str_unexpected_type() {
+        let left: Result<ParseStr<Path>, _> = ParamVal::from(1).try_into();
+        assert!(matches!(
+            left,
+            Err(TryIntoParamValError::UnexpectedType { .. })
+        ));
+    }
+}
@@ -0,0 +1,120 @@
+use attribution::AttrArgs;
+use attribution::ParamVal;
+use attribution::Parameters;
+use attribution::TryIntoParamValError;
+use pretty_assertions::assert_eq;
+use std::convert::TryFrom;
+
+#[allow(dead_code)]
+#[derive(AttrArgs, Debug)]
+struct Attr {
+    count: u8,
+    name: String,
+    enabled: bool,
+}
+
+#[allow(dead_code)]
+#[derive(AttrArgs, Debug)]
+enum EnumAttr {
+    Count { count: u8 },
+    Name { name: String },
+}
+
+/// Collects the message and starting column of every error.
+fn messages_and_columns(err: syn::parse::Error) -> Vec<(String, usize)> {
+    err.into_iter()
+        .map(|err| (err.to_string(), err.span().start().column))
+        .collect()
+}
+
+#[test]
+fn reports_every_field_error() {
+    let attr_args: Parameters =
+        syn::parse_str(r#"count = 300, name = 1, enabled = "yes", extra = 1"#).unwrap();
+
+    let err = Attr::try_from(attr_args).unwrap_err();
+    let expected = vec![
+        (
+            "The parameter 'count' expects a value that fits in `u8` but found 300.".to_string(),
+            8,
+        ),
+        (
+            "The parameter 'name' expects a value of type `String` but found an integer."
+                .to_string(),
+            20,
+        ),
+        (
+            "The parameter 'enabled' expects a value of type `bool` but found a string."
+                .to_string(),
+            33,
+        ),
+        (
+            "Unknown parameter 'extra'. Expected one of: 'count', 'name', 'enabled'.".to_string(),
+            40,
+        ),
+    ];
+    assert_eq!(messages_and_columns(err), expected);
+}
+
+#[test]
+fn reports_every_variant_error() {
+    let attr_args: Parameters = syn::parse_str(r#"count = 300"#).unwrap();
+
+    let err = EnumAttr::try_from(attr_args).unwrap_err();
+    let expected = vec![
+        ("No matching variant found.".to_string(), 0),
+        (
+            "The parameter 'count' expects a value that fits in `u8` but found 300.".to_string(),
+            8,
+        ),
+        ("The required parameter 'name' is missing.".to_string(), 0),
+        (
+            "Unknown parameter 'count'. Expected one of: 'name'.".to_string(),
+            0,
+        ),
+    ];
+    assert_eq!(messages_and_columns(err), expected);
+}
+
+const LIMIT: u32 = 5;
+
+#[derive(Debug, PartialEq)]
+enum Speed {
+    Slow,
+    Fast,
+}
+
+impl TryFrom<ParamVal> for Speed {
+    type Error = TryIntoParamValError;
+
+    fn try_from(param_val: ParamVal) -> Result<Self, Self::Error> {
+        match syn::Ident::try_from(param_val.clone())?
+            .to_string()
+            .as_str()
+        {
+            "Slow" => Ok(Speed::Slow),
+            "Fast" => Ok(Speed::Fast),
+            _ => Err(TryIntoParamValError::unexpected_type::<Self>(&param_val)),
+        }
+    }
+}
+
+#[derive(AttrArgs, Debug)]
+struct DefaultAttr {
+    #[attr_args(default = "LIMIT")]
+    limit: u32,
+    #[attr_args(default = "Speed::Slow")]
+    speed: Speed,
+}
+
+#[test]
+fn uses_const_and_unit_variant_defaults() {
+    let attr = DefaultAttr::try_from(Parameters::default()).unwrap();
+    assert_eq!(attr.limit, LIMIT);
+    assert_eq!(attr.speed, Speed::Slow);
+
+    let attr_args: Parameters = syn::parse_str("limit = 1, speed = Fast").unwrap();
+    let attr = DefaultAttr::try_from(attr_args).unwrap();
+    assert_eq!(attr.limit, 1);
+    assert_eq!(attr.speed, Speed::Fast);
+}